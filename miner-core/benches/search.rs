@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ore_app_miner_core::{best_response, Search, WebWorkerResponse};
+
+const CHALLENGE: [u8; 32] = *b"ore-app miner core test challeng";
+
+fn hash(c: &mut Criterion) {
+    let mut search = Search::new(CHALLENGE, 0);
+    c.bench_function("search step", |b| b.iter(|| search.step()));
+}
+
+fn batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("search batch");
+    group.sample_size(10);
+    group.bench_function("100 hashes", |b| {
+        b.iter_batched(
+            || Search::new(CHALLENGE, 0),
+            |mut search| {
+                search.run(100);
+                black_box(search.best())
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn select(c: &mut Criterion) {
+    let responses: Vec<WebWorkerResponse> = (0..64)
+        .map(|i| WebWorkerResponse {
+            hash: [0; 32],
+            digest: [0; 16],
+            nonce: [0; 8],
+            difficulty: (i * 7919) % 31,
+            offset: i as u64,
+            challenge: CHALLENGE,
+            power_level: 0,
+            target_difficulty: None,
+            batch: 0,
+            worker: 0,
+            hashes: 0,
+            elapsed_ms: 0,
+            histogram: vec![],
+        })
+        .collect();
+    c.bench_function("best response", |b| {
+        b.iter(|| best_response(black_box(&responses)).map(|res| res.offset))
+    });
+}
+
+criterion_group!(benches, hash, batch, select);
+criterion_main!(benches);
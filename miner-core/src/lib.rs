@@ -0,0 +1,213 @@
+//! The ORE solver loop, free of any browser or ui code, so the app can run it in web workers
+//! or native threads and it can be tested and benchmarked anywhere.
+
+mod stats;
+
+pub use stats::*;
+
+use serde::{Deserialize, Serialize};
+use web_time::Instant;
+
+/// Number of hashes between reads of the clock.
+pub const CLOCK_INTERVAL: u64 = 20;
+
+/// Number of hashes a search must process before it may stop.
+pub const MIN_HASHES: u64 = 100;
+
+/// Mining request for web workers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebWorkerRequest {
+    pub challenge: [u8; 32],
+    pub nonce: [u8; 8],
+    pub offset: u64,
+    pub cutoff_time: u64,
+    pub power_level: usize,
+    pub target_difficulty: Option<u32>,
+    pub batch: u64,
+    pub worker: usize,
+}
+
+/// Mining response for web workers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebWorkerResponse {
+    pub hash: [u8; 32],
+    pub digest: [u8; 16],
+    pub nonce: [u8; 8],
+    pub difficulty: u32,
+    pub offset: u64,
+    pub challenge: [u8; 32],
+    pub power_level: usize,
+    pub target_difficulty: Option<u32>,
+    pub batch: u64,
+    pub worker: usize,
+    pub hashes: u64,
+    pub elapsed_ms: u64,
+    /// Number of hashes found at each difficulty, indexed by difficulty.
+    pub histogram: Vec<u32>,
+}
+
+impl WebWorkerResponse {
+    /// Whether the worker stopped early because it found a hash at the target difficulty.
+    pub fn reached_target(&self) -> bool {
+        self.target_difficulty
+            .map_or(false, |target| self.difficulty.ge(&target))
+    }
+}
+
+/// The hardest hash a search has found.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Best {
+    pub hash: [u8; 32],
+    pub digest: [u8; 16],
+    pub nonce: [u8; 8],
+    pub difficulty: u32,
+}
+
+impl Best {
+    /// Keeps the hash if it is harder than the best so far. Returns whether it was kept.
+    pub fn update(&mut self, nonce: u64, hash: &drillx::Hash) -> bool {
+        let difficulty = hash.difficulty();
+        if difficulty.le(&self.difficulty) {
+            return false;
+        }
+        self.hash = hash.h;
+        self.digest = hash.d;
+        self.nonce = nonce.to_le_bytes();
+        self.difficulty = difficulty;
+        true
+    }
+}
+
+/// Hashes consecutive nonces of a challenge, tracking the hardest hash and the difficulty of
+/// every hash found.
+pub struct Search {
+    memory: drillx::equix::SolverMemory,
+    challenge: [u8; 32],
+    nonce: u64,
+    hashes: u64,
+    best: Best,
+    histogram: Vec<u32>,
+    started: Instant,
+}
+
+impl Search {
+    pub fn new(challenge: [u8; 32], nonce: u64) -> Self {
+        Self {
+            memory: drillx::equix::SolverMemory::new(),
+            challenge,
+            nonce,
+            hashes: 0,
+            best: Best::default(),
+            histogram: vec![],
+            started: Instant::now(),
+        }
+    }
+
+    /// The next nonce to hash.
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Number of nonces hashed so far.
+    pub fn hashes(&self) -> u64 {
+        self.hashes
+    }
+
+    pub fn best(&self) -> Best {
+        self.best
+    }
+
+    /// Number of hashes found at each difficulty, indexed by difficulty.
+    pub fn histogram(&self) -> &[u32] {
+        &self.histogram
+    }
+
+    /// Hashes the next nonce. Nonces whose equix puzzle has no solution are skipped.
+    pub fn step(&mut self) {
+        if let Ok(hash) =
+            drillx::hash_with_memory(&mut self.memory, &self.challenge, &self.nonce.to_le_bytes())
+        {
+            let difficulty = hash.difficulty() as usize;
+            if self.histogram.len().le(&difficulty) {
+                self.histogram.resize(difficulty + 1, 0);
+            }
+            self.histogram[difficulty] += 1;
+            self.best.update(self.nonce, &hash);
+        }
+        self.nonce = self.nonce.wrapping_add(1);
+        self.hashes += 1;
+    }
+
+    /// Hashes the next `count` nonces.
+    pub fn run(&mut self, count: u64) {
+        for _ in 0..count {
+            self.step();
+        }
+    }
+
+    /// Hashes the next `CLOCK_INTERVAL` nonces, stopping early at the request's target
+    /// difficulty. Returns whether the search is done.
+    pub fn step_interval(&mut self, req: &WebWorkerRequest) -> bool {
+        for _ in 0..CLOCK_INTERVAL {
+            self.step();
+            if self.reached(req.target_difficulty) {
+                return true;
+            }
+        }
+        is_past_cutoff(
+            self.hashes,
+            self.started.elapsed().as_secs(),
+            req.cutoff_time,
+        )
+    }
+
+    /// Whether the best hash is at or above the target difficulty, if there is one.
+    pub fn reached(&self, target_difficulty: Option<u32>) -> bool {
+        target_difficulty.map_or(false, |target| self.best.difficulty.ge(&target))
+    }
+
+    /// Builds the worker's response to a request. The offset advances by the number of nonces
+    /// hashed, so the next batch picks up where this one stopped.
+    pub fn response(&self, req: &WebWorkerRequest) -> WebWorkerResponse {
+        WebWorkerResponse {
+            hash: self.best.hash,
+            digest: self.best.digest,
+            nonce: self.best.nonce,
+            difficulty: self.best.difficulty,
+            offset: req.offset.saturating_add(self.hashes),
+            challenge: self.challenge,
+            power_level: req.power_level,
+            target_difficulty: req.target_difficulty,
+            batch: req.batch,
+            worker: req.worker,
+            hashes: self.hashes,
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            histogram: self.histogram.clone(),
+        }
+    }
+}
+
+/// Whether a search may stop, given the number of hashes processed and the seconds elapsed.
+pub fn is_past_cutoff(hashes: u64, elapsed: u64, cutoff_time: u64) -> bool {
+    hashes.ge(&MIN_HASHES) && elapsed.gt(&cutoff_time)
+}
+
+/// Searches from the request's nonce, and returns the hardest hash. The search stops as soon
+/// as it reaches the target difficulty, once the cutoff time has passed, or once it is
+/// cancelled. Cancellation is checked between intervals, every `CLOCK_INTERVAL` hashes.
+pub fn find_next_hash(
+    req: &WebWorkerRequest,
+    is_cancelled: impl Fn() -> bool,
+) -> WebWorkerResponse {
+    let mut search = Search::new(req.challenge, u64::from_le_bytes(req.nonce));
+    while !search.step_interval(req) && !is_cancelled() {}
+    search.response(req)
+}
+
+/// Picks the hardest of the workers' responses. Ties go to the earliest response.
+pub fn best_response(responses: &[WebWorkerResponse]) -> Option<&WebWorkerResponse> {
+    responses.iter().fold(None, |best, res| match best {
+        Some(best) if best.difficulty.ge(&res.difficulty) => Some(best),
+        _ => Some(res),
+    })
+}
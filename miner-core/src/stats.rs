@@ -0,0 +1,102 @@
+use crate::WebWorkerResponse;
+
+/// A worker's latest report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorkerStats {
+    pub batch: u64,
+    pub hashes: u64,
+    pub elapsed_ms: u64,
+}
+
+impl WorkerStats {
+    /// Hashes per second.
+    pub fn hashrate(&self) -> f64 {
+        if self.elapsed_ms.eq(&0) {
+            return 0.0;
+        }
+        self.hashes as f64 * 1000.0 / self.elapsed_ms as f64
+    }
+}
+
+/// The reward for a hash in base units, before the stake multiplier. Each difficulty above the
+/// minimum doubles the reward, up to `u64::MAX`, and easier hashes earn nothing.
+pub fn hash_reward(base_reward_rate: u64, difficulty: u32, min_difficulty: u32) -> u64 {
+    if difficulty.lt(&min_difficulty) || base_reward_rate.eq(&0) {
+        return 0;
+    }
+    2u64.checked_pow(difficulty - min_difficulty)
+        .and_then(|multiplier| base_reward_rate.checked_mul(multiplier))
+        .unwrap_or(u64::MAX)
+}
+
+/// Telemetry aggregated from the workers' responses. A round covers every batch searching the
+/// same challenge, and its stats reset when the challenge changes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MiningStats {
+    pub challenge: [u8; 32],
+    /// Each worker's latest report, indexed by worker.
+    pub workers: Vec<Option<WorkerStats>>,
+    /// Number of hashes found at each difficulty this round, indexed by difficulty.
+    pub histogram: Vec<u64>,
+    pub best_difficulty: u32,
+    /// Number of hashes attempted this round.
+    pub hashes: u64,
+    // Newest batch reported
+    batch: u64,
+}
+
+impl MiningStats {
+    /// Adds a response to the stats. Responses from batches older than the newest one reported
+    /// only count if they are for the same round.
+    pub fn record(&mut self, res: &WebWorkerResponse) {
+        if res.challenge.ne(&self.challenge) {
+            if res.batch.lt(&self.batch) {
+                return;
+            }
+            self.challenge = res.challenge;
+            self.histogram.clear();
+            self.best_difficulty = 0;
+            self.hashes = 0;
+        }
+        self.batch = self.batch.max(res.batch);
+
+        // Worker
+        if self.workers.len().le(&res.worker) {
+            self.workers.resize(res.worker + 1, None);
+        }
+        let worker = &mut self.workers[res.worker];
+        if worker.map_or(true, |stats| stats.batch.le(&res.batch)) {
+            *worker = Some(WorkerStats {
+                batch: res.batch,
+                hashes: res.hashes,
+                elapsed_ms: res.elapsed_ms,
+            });
+        }
+
+        // Round
+        if self.histogram.len().lt(&res.histogram.len()) {
+            self.histogram.resize(res.histogram.len(), 0);
+        }
+        for (count, n) in self.histogram.iter_mut().zip(res.histogram.iter()) {
+            *count += *n as u64;
+        }
+        self.best_difficulty = self.best_difficulty.max(res.difficulty);
+        self.hashes = self.hashes.saturating_add(res.hashes);
+    }
+
+    /// Workers which reported in the newest batch, with their index.
+    pub fn active_workers(&self) -> impl Iterator<Item = (usize, &WorkerStats)> {
+        self.workers
+            .iter()
+            .enumerate()
+            .filter_map(|(i, stats)| stats.as_ref().map(|stats| (i, stats)))
+            .filter(|(_, stats)| stats.batch.eq(&self.batch))
+    }
+
+    /// Combined hashes per second of the workers in the newest batch.
+    pub fn hashrate(&self) -> f64 {
+        self.active_workers()
+            .map(|(_, stats)| stats.hashrate())
+            .sum()
+    }
+}
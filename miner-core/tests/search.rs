@@ -0,0 +1,262 @@
+use std::cell::Cell;
+
+use ore_app_miner_core::{
+    best_response, find_next_hash, is_past_cutoff, Best, Search, WebWorkerRequest,
+    WebWorkerResponse, CLOCK_INTERVAL, MIN_HASHES,
+};
+
+const CHALLENGES: [[u8; 32]; 3] = [[0; 32], [255; 32], *b"ore-app miner core test challeng"];
+
+// Finds the hardest hash over a nonce range by calling drillx directly
+fn expected_best(challenge: [u8; 32], start: u64, count: u64) -> Best {
+    let mut best = Best::default();
+    for nonce in start..start + count {
+        if let Ok(hash) = drillx::hash(&challenge, &nonce.to_le_bytes()) {
+            best.update(nonce, &hash);
+        }
+    }
+    best
+}
+
+fn request(challenge: [u8; 32], offset: u64, cutoff_time: u64) -> WebWorkerRequest {
+    WebWorkerRequest {
+        challenge,
+        nonce: 0u64.to_le_bytes(),
+        offset,
+        cutoff_time,
+        power_level: 2,
+        target_difficulty: None,
+        batch: 7,
+        worker: 3,
+    }
+}
+
+fn response(difficulty: u32, offset: u64) -> WebWorkerResponse {
+    WebWorkerResponse {
+        hash: [0; 32],
+        digest: [0; 16],
+        nonce: [0; 8],
+        difficulty,
+        offset,
+        challenge: [0; 32],
+        power_level: 0,
+        target_difficulty: None,
+        batch: 0,
+        worker: 0,
+        hashes: 0,
+        elapsed_ms: 0,
+        histogram: vec![],
+    }
+}
+
+// Hashes of the third challenge at nonces 0 to 5, recorded from drillx 2.0. Nonce 5 has no
+// solution.
+const GOLDEN_NONCE: u64 = 1;
+const GOLDEN_DIGEST: [u8; 16] = [
+    77, 38, 122, 98, 11, 117, 235, 183, 170, 139, 41, 177, 202, 204, 12, 217,
+];
+const GOLDEN_HASH: [u8; 32] = [
+    5, 191, 193, 199, 67, 210, 50, 99, 20, 104, 159, 109, 193, 0, 225, 26, 23, 55, 13, 189, 209,
+    186, 68, 65, 175, 205, 186, 159, 27, 113, 142, 45,
+];
+const GOLDEN_DIFFICULTY: u32 = 5;
+const GOLDEN_HISTOGRAM: [u32; 6] = [2, 2, 0, 0, 0, 1];
+
+#[test]
+fn search_matches_golden_vector() {
+    let mut search = Search::new(CHALLENGES[2], 0);
+    search.run(6);
+    let best = search.best();
+    assert_eq!(best.nonce, GOLDEN_NONCE.to_le_bytes());
+    assert_eq!(best.digest, GOLDEN_DIGEST);
+    assert_eq!(best.hash, GOLDEN_HASH);
+    assert_eq!(best.difficulty, GOLDEN_DIFFICULTY);
+    assert_eq!(search.histogram(), GOLDEN_HISTOGRAM);
+    assert!(drillx::hash(&CHALLENGES[2], &5u64.to_le_bytes()).is_err());
+}
+
+#[test]
+fn search_finds_hardest_hash_in_range() {
+    for (i, challenge) in CHALLENGES.into_iter().enumerate() {
+        let start = (i as u64) * 1_000;
+        let mut search = Search::new(challenge, start);
+        search.run(40);
+        assert_eq!(search.best(), expected_best(challenge, start, 40));
+    }
+}
+
+#[test]
+fn search_best_is_a_valid_solution() {
+    for challenge in CHALLENGES {
+        let mut search = Search::new(challenge, 0);
+        search.run(40);
+        let best = search.best();
+        assert!(best.difficulty.gt(&0));
+        assert!(drillx::is_valid_digest(
+            &challenge,
+            &best.nonce,
+            &best.digest
+        ));
+        let hash = drillx::Solution::new(best.digest, best.nonce).to_hash();
+        assert_eq!(hash.h, best.hash);
+        assert_eq!(hash.difficulty(), best.difficulty);
+    }
+}
+
+#[test]
+fn search_is_deterministic() {
+    let mut a = Search::new(CHALLENGES[2], 42);
+    let mut b = Search::new(CHALLENGES[2], 42);
+    a.run(25);
+    b.run(25);
+    assert_eq!(a.best(), b.best());
+}
+
+#[test]
+fn search_resumes_where_it_stopped() {
+    // One search over a range finds the same best as two searches over its halves
+    let challenge = CHALLENGES[1];
+    let mut first = Search::new(challenge, 500);
+    first.run(20);
+    let mut second = Search::new(challenge, first.nonce());
+    second.run(20);
+    let mut whole = Search::new(challenge, 500);
+    whole.run(40);
+    let req = request(challenge, 0, 0);
+    let halves = best_response(&[first.response(&req), second.response(&req)])
+        .cloned()
+        .unwrap();
+    assert_eq!(halves.difficulty, whole.best().difficulty);
+    assert_eq!(halves.nonce, whole.best().nonce);
+}
+
+#[test]
+fn search_counts_difficulties() {
+    let challenge = CHALLENGES[2];
+    let mut search = Search::new(challenge, 0);
+    search.run(30);
+    let mut expected = vec![0u32; search.best().difficulty as usize + 1];
+    for nonce in 0..30u64 {
+        if let Ok(hash) = drillx::hash(&challenge, &nonce.to_le_bytes()) {
+            expected[hash.difficulty() as usize] += 1;
+        }
+    }
+    assert_eq!(search.histogram(), expected.as_slice());
+}
+
+#[test]
+fn search_counts_hashes_and_nonces() {
+    let mut search = Search::new(CHALLENGES[0], u64::MAX - 1);
+    search.run(3);
+    assert_eq!(search.hashes(), 3);
+    assert_eq!(search.nonce(), 1);
+}
+
+#[test]
+fn response_advances_offset() {
+    let mut search = Search::new(CHALLENGES[0], 7);
+    search.run(10);
+    let res = search.response(&request(CHALLENGES[1], 100, 0));
+    assert_eq!(res.offset, 110);
+    assert_eq!(res.challenge, CHALLENGES[0]);
+    assert_eq!(res.power_level, 2);
+    assert_eq!(res.batch, 7);
+    assert_eq!(res.worker, 3);
+    assert_eq!(res.hashes, 10);
+    assert_eq!(res.histogram, search.histogram());
+    assert_eq!(res.difficulty, search.best().difficulty);
+}
+
+#[test]
+fn best_update_keeps_only_harder_hashes() {
+    let mut h = [255; 32];
+    h[0] = 0;
+    let hash = drillx::Hash { d: [1; 16], h };
+    let mut best = Best::default();
+    assert!(best.update(0, &hash));
+    assert!(!best.update(1, &hash));
+    assert_eq!(best.nonce, 0u64.to_le_bytes());
+    assert_eq!(best.digest, [1; 16]);
+    assert_eq!(best.difficulty, 8);
+}
+
+#[test]
+fn cutoff_waits_for_time_and_min_hashes() {
+    assert!(!is_past_cutoff(MIN_HASHES, 10, 10));
+    assert!(!is_past_cutoff(MIN_HASHES - 1, 11, 10));
+    assert!(is_past_cutoff(MIN_HASHES, 11, 10));
+    assert!(is_past_cutoff(MIN_HASHES, 1, 0));
+}
+
+#[test]
+fn find_next_hash_stops_after_cutoff() {
+    let res = find_next_hash(&request(CHALLENGES[0], 50, 0), || false);
+    assert!(res.offset.ge(&(50 + MIN_HASHES)));
+    assert_eq!(res.power_level, 2);
+    assert!(!res.reached_target());
+    assert!(drillx::is_valid_digest(
+        &CHALLENGES[0],
+        &res.nonce,
+        &res.digest
+    ));
+}
+
+#[test]
+fn best_response_picks_hardest_first() {
+    assert!(best_response(&[]).is_none());
+    let responses = [
+        response(3, 0),
+        response(9, 1),
+        response(9, 2),
+        response(4, 3),
+    ];
+    assert_eq!(best_response(&responses).unwrap().offset, 1);
+    let responses = [response(0, 0), response(0, 1)];
+    assert_eq!(best_response(&responses).unwrap().offset, 0);
+}
+
+#[test]
+fn find_next_hash_stops_at_target() {
+    let challenge = CHALLENGES[2];
+    let expected = expected_best(challenge, 0, 40);
+    let mut req = request(challenge, 0, u64::MAX);
+    req.target_difficulty = Some(expected.difficulty);
+    let res = find_next_hash(&req, || false);
+    assert!(res.reached_target());
+    assert_eq!(res.difficulty, expected.difficulty);
+    assert_eq!(res.nonce, expected.nonce);
+    assert_eq!(res.offset, u64::from_le_bytes(expected.nonce) + 1);
+}
+
+#[test]
+fn find_next_hash_stops_when_cancelled() {
+    let checks = Cell::new(0);
+    let res = find_next_hash(&request(CHALLENGES[0], 0, u64::MAX), || {
+        checks.set(checks.get() + 1);
+        checks.get().gt(&1)
+    });
+    assert_eq!(res.offset, CLOCK_INTERVAL * 2);
+    assert!(!res.reached_target());
+}
+
+#[test]
+fn step_interval_hashes_an_interval() {
+    let req = request(CHALLENGES[1], 0, u64::MAX);
+    let mut search = Search::new(req.challenge, 0);
+    assert!(!search.step_interval(&req));
+    assert_eq!(search.hashes(), CLOCK_INTERVAL);
+    assert_eq!(
+        search.best(),
+        expected_best(req.challenge, 0, CLOCK_INTERVAL)
+    );
+}
+
+#[test]
+fn reached_target_needs_a_target() {
+    let mut res = response(12, 0);
+    assert!(!res.reached_target());
+    res.target_difficulty = Some(12);
+    assert!(res.reached_target());
+    res.target_difficulty = Some(13);
+    assert!(!res.reached_target());
+}
@@ -0,0 +1,113 @@
+use ore_app_miner_core::{hash_reward, MiningStats, WebWorkerResponse, WorkerStats};
+
+fn response(
+    challenge: [u8; 32],
+    batch: u64,
+    worker: usize,
+    hashes: u64,
+    histogram: Vec<u32>,
+) -> WebWorkerResponse {
+    WebWorkerResponse {
+        hash: [0; 32],
+        digest: [0; 16],
+        nonce: [0; 8],
+        difficulty: histogram.len().saturating_sub(1) as u32,
+        offset: 0,
+        challenge,
+        power_level: 1,
+        target_difficulty: None,
+        batch,
+        worker,
+        hashes,
+        elapsed_ms: 2_000,
+        histogram,
+    }
+}
+
+#[test]
+fn record_aggregates_a_round() {
+    let mut stats = MiningStats::default();
+    stats.record(&response([1; 32], 1, 0, 100, vec![40, 50, 10]));
+    stats.record(&response([1; 32], 1, 1, 200, vec![90, 110]));
+    assert_eq!(stats.challenge, [1; 32]);
+    assert_eq!(stats.hashes, 300);
+    assert_eq!(stats.histogram, vec![130, 160, 10]);
+    assert_eq!(stats.best_difficulty, 2);
+    assert_eq!(stats.hashrate(), 150.0);
+    assert_eq!(stats.active_workers().count(), 2);
+}
+
+#[test]
+fn record_resets_round_on_new_challenge() {
+    let mut stats = MiningStats::default();
+    stats.record(&response([1; 32], 1, 0, 100, vec![40, 50, 10]));
+    stats.record(&response([2; 32], 2, 0, 50, vec![25, 25]));
+    assert_eq!(stats.challenge, [2; 32]);
+    assert_eq!(stats.hashes, 50);
+    assert_eq!(stats.histogram, vec![25, 25]);
+    assert_eq!(stats.best_difficulty, 1);
+}
+
+#[test]
+fn record_skips_stale_rounds() {
+    let mut stats = MiningStats::default();
+    stats.record(&response([2; 32], 2, 0, 50, vec![25, 25]));
+    stats.record(&response([1; 32], 1, 1, 100, vec![40, 50, 10]));
+    assert_eq!(stats.challenge, [2; 32]);
+    assert_eq!(stats.hashes, 50);
+    assert!(stats.workers.get(1).is_none());
+}
+
+#[test]
+fn hashrate_counts_newest_batch_only() {
+    let mut stats = MiningStats::default();
+    stats.record(&response([1; 32], 1, 0, 100, vec![1]));
+    stats.record(&response([1; 32], 1, 1, 100, vec![1]));
+    stats.record(&response([1; 32], 2, 0, 400, vec![1]));
+    assert_eq!(stats.hashrate(), 200.0);
+    assert_eq!(stats.hashes, 600);
+    let active: Vec<usize> = stats.active_workers().map(|(i, _)| i).collect();
+    assert_eq!(active, vec![0]);
+}
+
+#[test]
+fn worker_keeps_newest_report() {
+    let mut stats = MiningStats::default();
+    stats.record(&response([1; 32], 3, 0, 300, vec![1]));
+    stats.record(&response([1; 32], 2, 0, 100, vec![1]));
+    assert_eq!(
+        stats.workers[0],
+        Some(WorkerStats {
+            batch: 3,
+            hashes: 300,
+            elapsed_ms: 2_000
+        })
+    );
+}
+
+#[test]
+fn worker_hashrate_without_elapsed_time() {
+    let stats = WorkerStats {
+        batch: 0,
+        hashes: 10,
+        elapsed_ms: 0,
+    };
+    assert_eq!(stats.hashrate(), 0.0);
+}
+
+#[test]
+fn hash_reward_doubles_per_difficulty() {
+    assert_eq!(hash_reward(1_000, 9, 10), 0);
+    assert_eq!(hash_reward(1_000, 10, 10), 1_000);
+    assert_eq!(hash_reward(1_000, 13, 10), 8_000);
+    assert_eq!(hash_reward(0, 50, 10), 0);
+}
+
+#[test]
+fn hash_reward_saturates() {
+    // Shifting would drop the high bits, and wrap these to 0
+    assert_eq!(hash_reward(1 << 30, 50, 10), u64::MAX);
+    assert_eq!(hash_reward(3, 73, 10), u64::MAX);
+    assert_eq!(hash_reward(1, 73, 10), 1 << 63);
+    assert_eq!(hash_reward(1, 74, 10), u64::MAX);
+}
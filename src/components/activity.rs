@@ -1,12 +1,15 @@
 use dioxus::prelude::*;
-use ore_types::{response::ListTransfersResponse, Transfer, TransferType};
 use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 use web_time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::{
     components::{Appearance, GlobeIcon, OreIcon, UserBubble, UserIcon},
-    hooks::{use_appearance, use_transfers, ActivityFilter, ACTIVITY_TABLE_PAGE_LIMIT},
+    gateway::{HistoryEntry, HistoryKind, HistoryPage},
+    hooks::{
+        address_name, use_address_book, use_appearance, use_transfers, ActivityFilter,
+        ACTIVITY_TABLE_PAGE_LIMIT,
+    },
     route::Route,
 };
 
@@ -111,7 +114,7 @@ pub fn FilterButtons(filter: Signal<ActivityFilter>, offset: Signal<u64>) -> Ele
 }
 
 #[component]
-pub fn ActivityTable(offset: Signal<u64>, transfers: ListTransfersResponse) -> Element {
+pub fn ActivityTable(offset: Signal<u64>, transfers: HistoryPage) -> Element {
     if transfers.data.is_empty() {
         rsx! {
             p {
@@ -175,7 +178,7 @@ pub fn ActivityTablePagination(offset: Signal<u64>, has_more: bool) -> Element {
 }
 
 #[component]
-pub fn ActivityRow(transfer: Transfer) -> Element {
+pub fn ActivityRow(transfer: HistoryEntry) -> Element {
     // TODO let pubkey = use_pubkey();
     let pubkey = Pubkey::new_from_array([0; 32]);
     let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
@@ -195,58 +198,69 @@ pub fn ActivityRow(transfer: Transfer) -> Element {
         format!("{}s ago", t)
     };
 
-    let action = match transfer.transfer_type {
-        TransferType::Claim => "claimed",
-        TransferType::Mine => "mined",
-        TransferType::Spl => "sent",
-        TransferType::Upgrade => "upgraded",
+    // Addresses in the address book show their label, and others the start of the address
+    let address_book = use_address_book();
+    let short_name = |address: &str| {
+        address_name(&address_book.read(), address)
+            .unwrap_or_else(|| address.chars().take(5).collect())
+    };
+    let name_class = |address: &str, name: &str| {
+        if name.eq("You") || address_name(&address_book.read(), address).is_some() {
+            "font-bold"
+        } else {
+            "font-mono font-bold"
+        }
     };
 
-    let addr_a = match transfer.transfer_type {
-        TransferType::Claim | TransferType::Mine | TransferType::Upgrade => {
+    let action = match transfer.kind {
+        HistoryKind::Claim => "claimed",
+        HistoryKind::Mine => "mined",
+        HistoryKind::Stake => "staked",
+        HistoryKind::Transfer => "sent",
+        HistoryKind::Upgrade => "upgraded",
+    };
+
+    let addr_a = match transfer.kind {
+        HistoryKind::Claim | HistoryKind::Mine | HistoryKind::Upgrade => {
             if transfer.to_address.eq(&pubkey.to_string()) {
                 "You".to_string()
             } else {
-                transfer.to_address[..5].to_string()
+                short_name(&transfer.to_address)
             }
         }
-        TransferType::Spl => {
+        HistoryKind::Stake | HistoryKind::Transfer => {
             if transfer.from_address.eq(&pubkey.to_string()) {
                 "You".to_string()
             } else {
-                transfer.from_address[..5].to_string()
+                short_name(&transfer.from_address)
             }
         }
     };
-    let addr_a_link = match transfer.transfer_type {
-        TransferType::Claim | TransferType::Mine | TransferType::Upgrade => {
+    let addr_a_link = match transfer.kind {
+        HistoryKind::Claim | HistoryKind::Mine | HistoryKind::Upgrade => {
             transfer.to_address.clone()
         }
-        TransferType::Spl => transfer.from_address,
-    };
-    let addr_a_class = if addr_a.eq(&"You".to_string()) {
-        "font-bold"
-    } else {
-        "font-mono font-bold"
+        HistoryKind::Stake | HistoryKind::Transfer => transfer.from_address,
     };
+    let addr_a_class = name_class(&addr_a_link, &addr_a);
 
     let addr_b = if transfer.to_address.eq(&pubkey.to_string()) {
         "You".to_string()
     } else {
-        match transfer.transfer_type {
-            TransferType::Claim | TransferType::Mine | TransferType::Upgrade => "".to_string(),
-            TransferType::Spl => transfer.to_address[..5].to_string(),
+        match transfer.kind {
+            HistoryKind::Claim | HistoryKind::Mine | HistoryKind::Stake | HistoryKind::Upgrade => {
+                "".to_string()
+            }
+            HistoryKind::Transfer => short_name(&transfer.to_address),
         }
     };
-    let addr_b_link = match transfer.transfer_type {
-        TransferType::Claim | TransferType::Mine | TransferType::Upgrade => "".to_string(),
-        TransferType::Spl => transfer.to_address,
-    };
-    let addr_b_class = if addr_b.eq(&"You".to_string()) {
-        "font-bold"
-    } else {
-        "font-mono font-bold"
+    let addr_b_link = match transfer.kind {
+        HistoryKind::Claim | HistoryKind::Mine | HistoryKind::Stake | HistoryKind::Upgrade => {
+            "".to_string()
+        }
+        HistoryKind::Transfer => transfer.to_address,
     };
+    let addr_b_class = name_class(&addr_b_link, &addr_b);
 
     rsx! {
         Link {
@@ -281,9 +295,9 @@ pub fn ActivityRow(transfer: Transfer) -> Element {
                             OreIcon {
                                 class: "ml-0.5 w-4 h-4 my-auto",
                             }
-                            "{amount_to_ui_amount(transfer.amount as u64, ore_api::consts::TOKEN_DECIMALS)}"
+                            "{amount_to_ui_amount(transfer.amount, ore_api::consts::TOKEN_DECIMALS)}"
                         }
-                        if let TransferType::Spl = transfer.transfer_type {
+                        if let HistoryKind::Transfer = transfer.kind {
                             "to"
                             Link {
                                 to: Route::User{ id: addr_b_link },
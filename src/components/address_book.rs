@@ -0,0 +1,217 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::WarningIcon,
+    hooks::{use_address_book, Contact, MAX_LABEL_LENGTH},
+    route::Route,
+};
+
+const EXPORT_FILENAME: &str = "ore-address-book.json";
+
+pub fn AddressBookSettings() -> Element {
+    let mut address_book = use_address_book();
+    let mut message = use_signal::<Option<Result<String, String>>>(|| None);
+    let export_url = format!(
+        "data:application/json;charset=utf-8,{}",
+        js_sys::encode_uri_component(&address_book.read().to_json())
+    );
+    let contacts = address_book.read().0.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            div {
+                class: "flex flex-row justify-between",
+                h2 {
+                    class: "text-lg md:text-2xl font-bold",
+                    "Address book"
+                }
+                div {
+                    class: "flex flex-row gap-4 my-auto text-sm font-semibold text-gray-300",
+                    label {
+                        class: "hover:underline cursor-pointer",
+                        "Import"
+                        input {
+                            class: "hidden",
+                            r#type: "file",
+                            accept: ".json,application/json",
+                            onchange: move |e| async move {
+                                let Some(file_engine) = e.files() else {
+                                    return;
+                                };
+                                let Some(name) = file_engine.files().first().cloned() else {
+                                    return;
+                                };
+                                let Some(json) = file_engine.read_file_to_string(&name).await else {
+                                    return;
+                                };
+                                let res = address_book.write().import(&json);
+                                message.set(Some(res.map(|count| format!("Imported {} contacts", count))));
+                            },
+                        }
+                    }
+                    if !contacts.is_empty() {
+                        a {
+                            class: "hover:underline",
+                            href: "{export_url}",
+                            download: EXPORT_FILENAME,
+                            "Export"
+                        }
+                    }
+                }
+            }
+            if let Some(message) = message.cloned() {
+                match message {
+                    Ok(msg) => rsx! {
+                        p {
+                            class: "text-sm text-green-500 font-semibold sm:px-1",
+                            "{msg}"
+                        }
+                    },
+                    Err(err) => rsx! {
+                        p {
+                            class: "flex flex-row gap-1.5 text-sm text-red-500 font-semibold sm:px-1",
+                            WarningIcon {
+                                class: "w-4 h-4 my-auto shrink-0"
+                            }
+                            "{err}"
+                        }
+                    },
+                }
+            }
+            if contacts.is_empty() {
+                p {
+                    class: "text-sm text-gray-300 sm:px-1",
+                    "Label addresses you send to often, and the labels are shown in place of their addresses."
+                }
+            }
+            for contact in contacts {
+                ContactRow {
+                    key: "{contact.address}",
+                    contact
+                }
+            }
+            NewContact {}
+        }
+    }
+}
+
+#[component]
+fn ContactRow(contact: Contact) -> Element {
+    let mut address_book = use_address_book();
+    let mut label_input = use_signal(|| contact.label.clone());
+    let mut is_editing = use_signal(|| false);
+    let address = contact.address.clone();
+    let save_address = contact.address.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-row gap-4 justify-between w-full sm:px-1",
+            div {
+                class: "flex flex-col min-w-0",
+                if *is_editing.read() {
+                    input {
+                        class: "bg-transparent font-medium outline-none border-b border-gray-300",
+                        autofocus: true,
+                        maxlength: MAX_LABEL_LENGTH as i64,
+                        value: "{label_input}",
+                        oninput: move |e| label_input.set(e.value()),
+                    }
+                } else {
+                    p {
+                        class: "font-medium truncate",
+                        "{contact.label}"
+                    }
+                }
+                Link {
+                    class: "font-mono text-sm text-gray-300 truncate hover:underline",
+                    to: Route::User { id: contact.address.clone() },
+                    "{contact.address}"
+                }
+            }
+            div {
+                class: "flex flex-row gap-3 my-auto text-sm font-semibold text-gray-300",
+                if *is_editing.read() {
+                    button {
+                        class: "hover:underline",
+                        onclick: move |_| {
+                            if address_book.write().insert(&save_address, &label_input.read()).is_ok() {
+                                is_editing.set(false);
+                            }
+                        },
+                        "Save"
+                    }
+                } else {
+                    button {
+                        class: "hover:underline",
+                        onclick: move |_| is_editing.set(true),
+                        "Edit"
+                    }
+                }
+                button {
+                    class: "hover:underline hover:text-red-500",
+                    onclick: move |_| address_book.write().remove(&address),
+                    "Delete"
+                }
+            }
+        }
+    }
+}
+
+fn NewContact() -> Element {
+    let mut address_book = use_address_book();
+    let mut address_input = use_signal(|| "".to_string());
+    let mut label_input = use_signal(|| "".to_string());
+    let mut error = use_signal::<Option<String>>(|| None);
+    let input_class =
+        "w-full bg-transparent outline-none placeholder-gray-200 dark:placeholder-gray-700 py-1";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 sm:px-1",
+            div {
+                class: "flex flex-col sm:flex-row gap-2",
+                input {
+                    class: "{input_class} font-mono sm:grow",
+                    placeholder: "Address",
+                    value: "{address_input}",
+                    oninput: move |e| address_input.set(e.value()),
+                }
+                input {
+                    class: "{input_class} sm:w-40",
+                    placeholder: "Label",
+                    maxlength: MAX_LABEL_LENGTH as i64,
+                    value: "{label_input}",
+                    oninput: move |e| label_input.set(e.value()),
+                }
+                button {
+                    class: "shrink-0 px-4 py-1 rounded font-semibold transition-colors transition-opacity hover-100 active-200 disabled:opacity-20",
+                    disabled: address_input.read().is_empty() || label_input.read().is_empty(),
+                    onclick: move |_| {
+                        let res = address_book
+                            .write()
+                            .insert(&address_input.read(), &label_input.read());
+                        match res {
+                            Ok(()) => {
+                                address_input.set("".to_string());
+                                label_input.set("".to_string());
+                                error.set(None);
+                            }
+                            Err(err) => error.set(Some(err)),
+                        }
+                    },
+                    "Add"
+                }
+            }
+            if let Some(err) = error.cloned() {
+                p {
+                    class: "flex flex-row gap-1.5 text-sm text-red-500 font-semibold",
+                    WarningIcon {
+                        class: "w-4 h-4 my-auto shrink-0"
+                    }
+                    "{err}"
+                }
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ use dioxus::prelude::*;
 #[derive(Clone, PartialEq)]
 pub enum BannerStyle {
     Info,
+    Warning,
     Error,
 }
 
@@ -11,6 +12,7 @@ pub enum BannerStyle {
 pub fn Banner(style: BannerStyle, link_to: Option<String>, children: Element) -> Element {
     let color = match style {
         BannerStyle::Info => "bg-blue-500 text-white",
+        BannerStyle::Warning => "bg-yellow-500 text-black",
         BannerStyle::Error => "bg-red-500 text-white",
     };
     rsx! {
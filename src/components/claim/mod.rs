@@ -29,12 +29,12 @@ pub fn Claim() -> Element {
     // TODO Calc if escrow proof is present
 
     let max_rewards = escrow_proof
-        .read()
+        .cloned()
         .and_then(|p| p.ok())
         .map(|p| p.balance)
         .unwrap_or_else(|| {
             proof
-                .read()
+                .cloned()
                 .and_then(|p| p.ok())
                 .map(|p| p.balance)
                 .unwrap_or_else(|| 0)
@@ -1,21 +1,17 @@
 use std::borrow::BorrowMut;
 
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, transaction::Transaction,
-};
-use solana_extra_wasm::program::{
-    spl_associated_token_account::instruction::create_associated_token_account,
-    spl_token::{self, amount_to_ui_amount},
-};
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 
 use crate::{
-    components::{BackButton, InvokeSignature, OreIcon},
-    gateway::{self, ore_token_account_address_v1},
+    components::{BackButton, ErrorMessage, InvokeSignature, OreIcon},
+    gateway::{FeeAction, GatewayError},
     hooks::{
-        use_gateway,
+        use_gateway, use_lookup_table, use_priority_fee,
         use_wallet_adapter::{use_wallet_adapter, InvokeSignatureStatus, WalletAdapter},
+        TransactionKind,
     },
+    tx,
 };
 
 use super::ClaimV1Step;
@@ -25,43 +21,21 @@ pub fn ClaimV1Confirm(amount: u64, claim_step: Signal<ClaimV1Step>) -> Element {
     // let mut ore_balance = use_ore_balance();
     let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
     let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
 
-    let tx = use_resource(move || {
-        async move {
-            if let WalletAdapter::Connected(signer) = *wallet_adapter.read() {
-                // Cu limit
-                let price = gateway::get_recent_priority_fee_estimate(false).await;
-                let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(50_000);
-                let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                let mut ixs = vec![cu_limit_ix, cu_price_ix];
-                let token_account_address = ore_token_account_address_v1(signer);
-
-                // Add create ata ix
-                let gateway = use_gateway();
-                if let Ok(Some(_)) = gateway.get_token_account(&token_account_address).await {
-                } else {
-                    ixs.push(create_associated_token_account(
-                        &signer,
-                        &signer,
-                        &ore_api_v1::MINT_ADDRESS,
-                        &spl_token::id(),
-                    ));
-                }
-
-                // Add transfer
-                ixs.push(ore_api_v1::instruction::claim(
-                    signer,
-                    token_account_address,
-                    amount,
-                ));
-
-                // Return tx
-                let mut tx = Transaction::new_with_payer(&ixs, Some(&signer));
-                tx.message.recent_blockhash = gateway.rpc.get_latest_blockhash().await.unwrap();
-                Some(tx)
-            } else {
-                None
-            }
+    let tx = use_resource(move || async move {
+        if let WalletAdapter::Connected(signer) = *wallet_adapter.read() {
+            let gateway = use_gateway();
+            let price = gateway
+                .get_priority_fee(*priority_fee.read(), FeeAction::Claim)
+                .await;
+            let builder = tx::claim_v1(signer, amount).priority_fee(price);
+            gateway
+                .build_transaction(builder, lookup_table.read().0)
+                .await
+        } else {
+            Err(GatewayError::WalletAdapterDisconnected)
         }
     });
 
@@ -108,8 +82,10 @@ pub fn ClaimV1Confirm(amount: u64, claim_step: Signal<ClaimV1Step>) -> Element {
                     }
                 }
             }
-            if let Some(Some(tx)) = tx.cloned() {
-                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm" }
+            if let Some(Ok(tx)) = tx.cloned() {
+                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm", kind: TransactionKind::ClaimV1, amount: amount }
+            } else if let Some(Err(err)) = tx.cloned() {
+                ErrorMessage { err }
             } else {
                 p {
                     class: "font-medium text-center text-sm text-gray-300 hover:underline",
@@ -25,7 +25,7 @@ pub fn ClaimV1() -> Element {
         Err(_) => 0,
     };
     let max_rewards = proof
-        .read()
+        .cloned()
         .and_then(|p| p.ok())
         .map(|p| p.claimable_rewards)
         .unwrap_or_else(|| 0);
@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+
+use crate::{components::CodeBlock, gateway::GatewayError};
+
+#[component]
+pub fn ErrorMessage(err: GatewayError) -> Element {
+    let logs = match &err {
+        GatewayError::SimulationFailed { logs, .. } if !logs.is_empty() => Some(logs.join("\n")),
+        _ => None,
+    };
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            p {
+                class: "text-sm text-red-500 text-center break-words",
+                "{err}"
+            }
+            if let Some(logs) = logs {
+                details {
+                    class: "text-sm",
+                    summary {
+                        class: "text-gray-300 cursor-pointer",
+                        "Program logs"
+                    }
+                    div {
+                        class: "mt-2 text-xs",
+                        CodeBlock {
+                            text: logs
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -7,13 +7,13 @@ use solana_sdk::pubkey::Pubkey;
 use crate::{
     components::{BackButton, CreateAccountPage, MigrateAccountPage, OreIcon, Spinner},
     hooks::{
-        use_escrow, use_gateway, use_miner_toolbar_state, use_power_level, use_proof, MinerStatus,
-        MinerStatusMessage, PowerLevel, ReadMinerToolbarState,
+        optimal_difficulty, use_escrow, use_gateway, use_miner_toolbar_state, use_mining_stats,
+        use_power_level, use_proof, use_target_difficulty, MinerStatus, MinerStatusMessage,
+        PowerLevel, ReadMinerToolbarState, TargetDifficulty,
     },
-    miner::WEB_WORKERS,
+    miner::{hash_reward, WEB_WORKERS},
 };
 
-// TODO Activity history of hashes
 // TODO Display for non-active states
 // TODO Stop start button
 
@@ -31,12 +31,10 @@ pub fn Mine() -> Element {
         };
     }
 
-    if let Some(proof_result) = *proof.read() {
-        if proof_result.is_err() {
-            return rsx! {
-                CreateAccountPage {}
-            };
-        }
+    if let Some(Err(_)) = *proof.read() {
+        return rsx! {
+            CreateAccountPage {}
+        };
     }
 
     rsx! {
@@ -62,7 +60,9 @@ pub fn Mine() -> Element {
                 StakeBalanceDisplay {}
                 MultiplierDisplay {}
                 PowerLevelConfig {}
+                TargetDifficultyConfig {}
             }
+            MiningStatsDisplay {}
             // BoostConfig {}
         }
     }
@@ -105,11 +105,11 @@ pub fn MinerStatus() -> Element {
                                 }
                             }
                         }
-                        MinerStatusMessage::Error => {
+                        MinerStatusMessage::Error(err) => {
                             rsx! {
                                 p {
                                     class: "text-lg text-white",
-                                    "Error submitting transaction"
+                                    "Error submitting transaction: {err}"
                                 }
                             }
                         }
@@ -152,7 +152,7 @@ pub fn StakeBalanceDisplay() -> Element {
                     }
                div {
                     class: "flex flex-row flex-shrink h-min gap-1 shrink mb-auto",
-                    if let Some(proof) = *proof.read() {
+                    if let Some(proof) = proof.cloned() {
                         if let Ok(proof) = proof {
                             div {
                                 class: "flex flex-row gap-2",
@@ -299,3 +299,252 @@ pub fn PowerLevelConfig() -> Element {
         }
     }
 }
+
+pub fn TargetDifficultyConfig() -> Element {
+    let mut target_difficulty = use_target_difficulty();
+    let difficulties = use_resource(move || async move {
+        use_gateway()
+            .get_config()
+            .await
+            .map(|config| {
+                let min = config.min_difficulty as u32;
+                (min, optimal_difficulty(min, config.base_reward_rate))
+            })
+            .unwrap_or((0, 0))
+    });
+    let (min, optimal) = difficulties.cloned().unwrap_or((0, 0));
+    let description = match *target_difficulty.read() {
+        TargetDifficulty::Off => {
+            "Workers search until the time runs out, and the hardest hash is submitted.".to_string()
+        }
+        TargetDifficulty::Optimal => format!(
+            "Workers stop once a hash reaches difficulty {}, past which rewards can't grow. The hash is submitted when the minute is up.",
+            optimal
+        ),
+        TargetDifficulty::Fixed(difficulty) => format!(
+            "Workers stop once a hash reaches difficulty {}, to save power. The hash is submitted when the minute is up.",
+            difficulty.max(min)
+        ),
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            div {
+                class: "flex flex-row gap-8 justify-between",
+                p {
+                    class: "text-gray-300 font-medium text-sm my-auto",
+                    "Target difficulty"
+                }
+                div {
+                    class: "flex flex-row flex-shrink h-min gap-2 shrink mb-auto",
+                    if let TargetDifficulty::Fixed(difficulty) = *target_difficulty.read() {
+                        input {
+                            class: "bg-transparent text-white text-right px-1 w-16 mb-auto rounded font-semibold transition-colors",
+                            dir: "rtl",
+                            step: 1,
+                            min: min as i64,
+                            r#type: "number",
+                            value: "{difficulty}",
+                            oninput: move |e| {
+                                if let Ok(v) = e.value().parse::<u32>() {
+                                    target_difficulty.set(TargetDifficulty::Fixed(v));
+                                }
+                            }
+                        }
+                    }
+                    select {
+                        class: "text-right bg-transparent text-white font-semibold hover:cursor-pointer",
+                        onchange: move |e| {
+                            match TargetDifficulty::from_str(&e.value()) {
+                                Ok(TargetDifficulty::Fixed(_)) => target_difficulty.set(TargetDifficulty::Fixed(min)),
+                                Ok(t) => target_difficulty.set(t),
+                                Err(_) => {}
+                            }
+                        },
+                        option { initial_selected: target_difficulty.read().eq(&TargetDifficulty::Off), value: "{TargetDifficulty::Off}", "{TargetDifficulty::Off}" }
+                        option { initial_selected: target_difficulty.read().eq(&TargetDifficulty::Optimal), value: "{TargetDifficulty::Optimal}", "{TargetDifficulty::Optimal}" }
+                        option { initial_selected: matches!(*target_difficulty.read(), TargetDifficulty::Fixed(_)), value: "{TargetDifficulty::Fixed(0)}", "{TargetDifficulty::Fixed(0)}" }
+                    }
+                }
+            }
+            p {
+                class: "text-sm text-gray-300",
+                "{description}"
+            }
+        }
+    }
+}
+
+pub fn MiningStatsDisplay() -> Element {
+    let mining_stats = use_mining_stats();
+    let proof = use_proof();
+    let stats = mining_stats.read().clone();
+
+    // Reward for the best hash this round, at the current multiplier
+    let reward = use_resource(move || async move {
+        let best_difficulty = mining_stats.read().best_difficulty;
+        let config = use_gateway().get_config().await.ok()?;
+        let reward = hash_reward(
+            config.base_reward_rate,
+            best_difficulty,
+            config.min_difficulty as u32,
+        );
+        let multiplier = match *proof.read() {
+            Some(Ok(proof)) => 1.0 + (proof.balance as f64 / config.top_balance as f64).min(1.0f64),
+            _ => 1.0,
+        };
+        Some(amount_to_ui_amount(reward, ore_api::consts::TOKEN_DECIMALS) * multiplier)
+    });
+
+    let cores: Vec<(usize, f64)> = (0..*WEB_WORKERS)
+        .map(|i| {
+            let hashrate = stats
+                .active_workers()
+                .find(|(worker, _)| worker.eq(&i))
+                .map(|(_, worker)| worker.hashrate())
+                .unwrap_or(0.0);
+            (i, hashrate)
+        })
+        .collect();
+    let max_core_hashrate = cores.iter().map(|(_, h)| *h).fold(0.0, f64::max);
+    let max_count = stats.histogram.iter().copied().max().unwrap_or(0);
+    let distribution: Vec<(usize, u64)> = stats
+        .histogram
+        .iter()
+        .copied()
+        .enumerate()
+        .skip_while(|(_, count)| count.eq(&0))
+        .collect();
+
+    let row_class = "flex flex-row gap-8 justify-between";
+    let title_class = "text-gray-300 font-medium text-sm my-auto";
+
+    if stats.hashes.eq(&0) {
+        return rsx! {
+            p {
+                class: "text-sm text-gray-300",
+                "Mining stats appear here once your cores report their first batch."
+            }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-6",
+            div {
+                class: "{row_class}",
+                p {
+                    class: "{title_class}",
+                    "Hashrate"
+                }
+                p {
+                    class: "font-semibold",
+                    "{stats.hashrate():.1} H/s"
+                }
+            }
+            div {
+                class: "{row_class}",
+                p {
+                    class: "{title_class}",
+                    "Best difficulty"
+                }
+                p {
+                    class: "font-semibold",
+                    "{stats.best_difficulty}"
+                }
+            }
+            div {
+                class: "{row_class}",
+                p {
+                    class: "{title_class}",
+                    "Estimated reward"
+                }
+                div {
+                    class: "flex flex-row gap-2",
+                    OreIcon {
+                        class: "my-auto w-4 h-4"
+                    }
+                    p {
+                        class: "font-semibold",
+                        if let Some(Some(reward)) = *reward.read() {
+                            "{reward:.6}"
+                        } else {
+                            "–"
+                        }
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-2",
+                p {
+                    class: "{title_class}",
+                    "Hashrate per core"
+                }
+                p {
+                    class: "text-xs text-gray-300",
+                    "Bars are relative to the fastest core."
+                }
+                for (i, hashrate) in cores {
+                    div {
+                        class: "flex flex-row gap-4",
+                        p {
+                            class: "text-sm text-gray-300 w-8 shrink-0",
+                            "{i + 1}"
+                        }
+                        div {
+                            class: "flex grow h-2 my-auto rounded-full bg-gray-100 dark:bg-gray-900",
+                            div {
+                                class: "h-2 rounded-full bg-green-500",
+                                style: "width: {bar_width(hashrate, max_core_hashrate)}%",
+                            }
+                        }
+                        p {
+                            class: "text-sm font-mono text-right w-24 shrink-0",
+                            if hashrate.gt(&0.0) {
+                                "{hashrate:.1} H/s"
+                            } else {
+                                "Idle"
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-2",
+                p {
+                    class: "{title_class}",
+                    "Difficulty distribution"
+                }
+                for (difficulty, count) in distribution {
+                    div {
+                        class: "flex flex-row gap-4",
+                        p {
+                            class: "text-sm text-gray-300 w-8 shrink-0",
+                            "{difficulty}"
+                        }
+                        div {
+                            class: "flex grow h-2 my-auto",
+                            div {
+                                class: "h-2 rounded-full bg-gray-300",
+                                style: "width: {bar_width(count as f64, max_count as f64)}%",
+                            }
+                        }
+                        p {
+                            class: "text-sm font-mono text-right w-24 shrink-0",
+                            "{count}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Width of a bar relative to the longest one, as a percentage
+fn bar_width(value: f64, max: f64) -> f64 {
+    if max.le(&0.0) {
+        return 0.0;
+    }
+    (value / max * 100.0).clamp(0.0, 100.0)
+}
@@ -26,7 +26,7 @@ pub fn MinerToolbarActivating(miner: Signal<Miner>) -> Element {
                 Err(err) => {
                     log::error!("Failed to start mining: {:?}", err);
                     toolbar_state.set_status(MinerStatus::Error);
-                    toolbar_state.set_status_message(MinerStatusMessage::Error);
+                    toolbar_state.set_status_message(MinerStatusMessage::Error(err));
                 }
             }
         }
@@ -49,11 +49,11 @@ pub fn MinerToolbarActive(miner: Signal<Miner>) -> Element {
                             }
                         }
                     }
-                    MinerStatusMessage::Error => {
+                    MinerStatusMessage::Error(err) => {
                         rsx! {
                             p {
                                 class: "truncate flex-shrink flex-auto text-sm text-white opacity-80 my-auto ml-2",
-                                "Error submitting transaction"
+                                "Error submitting transaction: {err}"
                             }
                         }
                     }
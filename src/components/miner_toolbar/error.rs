@@ -21,11 +21,11 @@ pub fn MinerToolbarError() -> Element {
                 //     "RPC rate limited."
                 // }
                 match toolbar_state.status_message() {
-                    MinerStatusMessage::Error => {
+                    MinerStatusMessage::Error(err) => {
                         rsx! {
                             p {
                                 class: "truncate flex-shrink flex-auto text-sm text-white opacity-80 my-auto ml-2",
-                                "Error submitting transaction"
+                                "{err}"
                             }
                         }
                     }
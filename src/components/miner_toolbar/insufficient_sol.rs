@@ -1,32 +1,23 @@
-use std::ops::Div;
-
 use dioxus::prelude::*;
 use ore_api::state::Proof;
 use ore_relayer_api::state::Escrow;
-use solana_client_wasm::solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, message::Message, native_token::sol_to_lamports,
-    pubkey, pubkey::Pubkey, transaction::Transaction,
-};
-use solana_extra_wasm::program::{
-    spl_associated_token_account::instruction::create_associated_token_account, spl_token,
-};
 use web_time::Duration;
 
 use crate::{
-    components::{BackButton, InfoIcon, InvokeSignature},
-    gateway::{self, escrow_pubkey, ore_token_account_address, GatewayError, GatewayResult},
+    components::{BackButton, ErrorMessage, InfoIcon, InvokeSignature},
+    gateway::{FeeAction, GatewayError, GatewayResult},
     hooks::{
-        use_gateway, use_proof,
+        use_gateway, use_lookup_table, use_priority_fee,
         use_wallet_adapter::{use_wallet_adapter, InvokeSignatureStatus, WalletAdapter},
     },
+    tx,
 };
 
-const TOP_UP_AMOUNT: f64 = 0.02; // In SOL (~$2)
-const COLLECTION_ADDRESS: Pubkey = pubkey!("tHCCE3KWKx8i8cDjX2DQ3Z7EMJkScAVwkfxdWz8SqgP");
-
 #[component]
 pub fn MinerToolbarTopUpOpen(escrow_balance: Resource<GatewayResult<u64>>) -> Element {
     let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
     let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
     let nav = use_navigator();
 
@@ -35,23 +26,13 @@ pub fn MinerToolbarTopUpOpen(escrow_balance: Resource<GatewayResult<u64>>) -> El
             WalletAdapter::Disconnected => Err(GatewayError::WalletAdapterDisconnected),
             WalletAdapter::Connected(signer) => {
                 let gateway = use_gateway();
-                let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(100_000);
-                let amount = sol_to_lamports(TOP_UP_AMOUNT);
-                let ix_1 = solana_client_wasm::solana_sdk::system_instruction::transfer(
-                    &signer,
-                    &escrow_pubkey(signer),
-                    amount,
-                );
-                let ix_2 = solana_client_wasm::solana_sdk::system_instruction::transfer(
-                    &signer,
-                    &COLLECTION_ADDRESS,
-                    amount.div(100), // 1% fee
-                );
-                let blockhash = gateway.rpc.get_latest_blockhash().await?;
-                let ixs = vec![cu_limit_ix, ix_1, ix_2];
-                let msg = Message::new_with_blockhash(ixs.as_slice(), Some(&signer), &blockhash);
-                let tx = Transaction::new_unsigned(msg);
-                Ok(tx)
+                let price = gateway
+                    .get_priority_fee(*priority_fee.read(), FeeAction::TopUp)
+                    .await;
+                let builder = tx::top_up(signer).priority_fee(price);
+                gateway
+                    .build_transaction(builder, lookup_table.read().0)
+                    .await
             }
         }
     });
@@ -96,6 +77,8 @@ pub fn MinerToolbarTopUpOpen(escrow_balance: Resource<GatewayResult<u64>>) -> El
                 class: "flex flex-col gap-4",
                 if let Some(Ok(tx)) = tx.cloned() {
                     InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Top up" }
+                } else if let Some(Err(err)) = tx.cloned() {
+                    ErrorMessage { err }
                 } else {
                     p {
                         class: "font-medium text-center text-sm text-gray-300 hover:underline",
@@ -116,8 +99,9 @@ pub fn MinerToolbarTopUpOpen(escrow_balance: Resource<GatewayResult<u64>>) -> El
 
 pub fn CreateAccountPage() -> Element {
     let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
     let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
-    let mut proof = use_proof();
     let nav = use_navigator();
 
     let tx = use_resource(move || async move {
@@ -125,41 +109,17 @@ pub fn CreateAccountPage() -> Element {
             WalletAdapter::Disconnected => Err(GatewayError::WalletAdapterDisconnected),
             WalletAdapter::Connected(signer) => {
                 let gateway = use_gateway();
-                let price = gateway::get_recent_priority_fee_estimate(false).await;
-                let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(500_000);
-                let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                let amount = sol_to_lamports(TOP_UP_AMOUNT);
-                let ix_1 = ore_api::instruction::open(signer, signer, signer);
-                // TODO This is commented out because users are currently manually signing (not escrow)
-                // let ix_2 = solana_client_wasm::solana_sdk::system_instruction::transfer(
-                //     &signer,
-                //     &escrow_pubkey(signer),
-                //     amount,
-                // );
-                let ix_3 = solana_client_wasm::solana_sdk::system_instruction::transfer(
-                    &signer,
-                    &COLLECTION_ADDRESS,
-                    amount.div(100), // 1% fee
-                );
-                let blockhash = gateway.rpc.get_latest_blockhash().await?;
-                let ixs = vec![cu_limit_ix, cu_price_ix, ix_1, ix_3];
-                let msg = Message::new_with_blockhash(ixs.as_slice(), Some(&signer), &blockhash);
-                let tx = Transaction::new_unsigned(msg);
-                Ok(tx)
+                let price = gateway
+                    .get_priority_fee(*priority_fee.read(), FeeAction::Open)
+                    .await;
+                let builder = tx::open(signer).priority_fee(price);
+                gateway
+                    .build_transaction(builder, lookup_table.read().0)
+                    .await
             }
         }
     });
 
-    let _ = use_resource(move || async move {
-        if let InvokeSignatureStatus::Done(_sig) = *invoke_signature_signal.read() {
-            if let WalletAdapter::Connected(_signer) = *wallet_adapter.read() {
-                async_std::task::sleep(Duration::from_millis(2000)).await;
-                proof.restart();
-            }
-        };
-        ()
-    });
-
     rsx! {
         div {
             class: "flex flex-col h-full w-full grow gap-12 sm:gap-16 justify-between",
@@ -190,6 +150,8 @@ pub fn CreateAccountPage() -> Element {
                 class: "flex flex-col gap-4",
                 if let Some(Ok(tx)) = tx.cloned() {
                     InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Create account" }
+                } else if let Some(Err(err)) = tx.cloned() {
+                    ErrorMessage { err }
                 } else {
                     p {
                         class: "font-medium text-center text-sm text-gray-300 hover:underline",
@@ -214,6 +176,8 @@ pub fn MigrateAccountPage(
     escrow: Resource<GatewayResult<Escrow>>,
 ) -> Element {
     let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
     let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
     let nav = use_navigator();
 
@@ -222,27 +186,13 @@ pub fn MigrateAccountPage(
             WalletAdapter::Disconnected => Err(GatewayError::WalletAdapterDisconnected),
             WalletAdapter::Connected(signer) => {
                 let gateway = use_gateway();
-                let price = gateway::get_recent_priority_fee_estimate(false).await;
-                let mut ixs = vec![];
-                ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(1_400_000));
-                ixs.push(ComputeBudgetInstruction::set_compute_unit_price(price));
-                let token_account_address = ore_token_account_address(signer);
-                if let Ok(Some(_)) = gateway.get_token_account(&token_account_address).await {
-                } else {
-                    ixs.push(create_associated_token_account(
-                        &signer,
-                        &signer,
-                        &ore_api::consts::MINT_ADDRESS,
-                        &spl_token::id(),
-                    ));
-                }
-                ixs.push(ore_relayer_api::instruction::migrate(
-                    signer, signer, signer,
-                ));
-                let blockhash = gateway.rpc.get_latest_blockhash().await?;
-                let msg = Message::new_with_blockhash(ixs.as_slice(), Some(&signer), &blockhash);
-                let tx = Transaction::new_unsigned(msg);
-                Ok(tx)
+                let price = gateway
+                    .get_priority_fee(*priority_fee.read(), FeeAction::Migrate)
+                    .await;
+                let builder = tx::migrate(signer).priority_fee(price);
+                gateway
+                    .build_transaction(builder, lookup_table.read().0)
+                    .await
             }
         }
     });
@@ -317,6 +267,8 @@ pub fn MigrateAccountPage(
                 class: "flex flex-col gap-4",
                 if let Some(Ok(tx)) = tx.cloned() {
                     InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Migrate" }
+                } else if let Some(Err(err)) = tx.cloned() {
+                    ErrorMessage { err }
                 } else {
                     p {
                         class: "font-medium text-center text-sm text-gray-300 hover:underline",
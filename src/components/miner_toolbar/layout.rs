@@ -1,9 +1,14 @@
 use dioxus::prelude::*;
 
-use crate::{components::MinerToolbar, hooks::use_miner_toolbar_state_provider, Route};
+use crate::{
+    components::MinerToolbar,
+    hooks::{use_miner_toolbar_state_provider, use_mining_stats_provider},
+    Route,
+};
 
 pub fn MinerToolbarLayout() -> Element {
     use_miner_toolbar_state_provider();
+    use_mining_stats_provider();
     let route = use_route::<Route>();
     let hidden = !matches!(
         route,
@@ -1,5 +1,6 @@
 mod activity;
 mod activity_indicator;
+mod address_book;
 mod appearance;
 mod back_button;
 mod balance;
@@ -9,6 +10,7 @@ mod claim_v1;
 mod code_block;
 mod copyable;
 mod download;
+mod error_message;
 mod mine;
 mod pay;
 mod stake;
@@ -19,9 +21,11 @@ mod icons;
 mod landing;
 mod miner_toolbar;
 mod navbar;
+mod notifications;
 mod ore_tokenomics;
 mod page_not_found;
 mod send;
+mod send_batch;
 mod settings;
 mod spinner;
 mod stop_button;
@@ -33,6 +37,7 @@ mod wallet_adapter;
 mod what_is_mining;
 
 pub use activity::*;
+pub use address_book::*;
 pub use appearance::*;
 pub use back_button::*;
 pub use balance::*;
@@ -42,6 +47,7 @@ pub use claim_v1::*;
 pub use code_block::*;
 pub use copyable::*;
 pub use download::*;
+pub use error_message::*;
 pub use footer::*;
 pub use home::*;
 pub use icons::*;
@@ -49,10 +55,12 @@ pub use landing::*;
 pub use mine::*;
 pub use miner_toolbar::*;
 pub use navbar::*;
+pub use notifications::*;
 pub use ore_tokenomics::*;
 pub use page_not_found::*;
 pub use pay::*;
 pub use send::*;
+pub use send_batch::*;
 pub use settings::*;
 pub use spinner::*;
 pub use stake::*;
@@ -2,15 +2,16 @@ use dioxus::prelude::*;
 
 use crate::{
     components::{
-        Appearance, Banner, BannerStyle, CogIcon, Footer, MountWalletAdapter, OreLogoIcon,
-        OreWordmarkIcon,
+        Appearance, Banner, BannerStyle, CogIcon, Footer, MountWalletAdapter, NotificationsButton,
+        OreLogoIcon, OreWordmarkIcon,
     },
-    hooks::use_appearance,
+    hooks::{use_appearance, use_cluster, Cluster},
     route::Route,
 };
 
 pub fn Navbar() -> Element {
     let appearance = use_appearance();
+    let cluster = use_cluster();
     let dark = match *appearance.read() {
         Appearance::Dark => "dark",
         Appearance::Light => "",
@@ -18,6 +19,13 @@ pub fn Navbar() -> Element {
     rsx! {
         div {
             class: "relative min-h-screen flex flex-col text-black dark:bg-black dark:text-white {dark}",
+            if cluster.read().ne(&Cluster::Mainnet) {
+                Banner {
+                    style: BannerStyle::Warning,
+                    link_to: Route::Settings {}.to_string(),
+                    "You are connected to {cluster}. Tokens on this network have no value."
+                }
+            }
             Banner {
                 style: BannerStyle::Info,
                 "Mining on this page may be throttled if left in the background."
@@ -35,6 +43,7 @@ pub fn Navbar() -> Element {
                     }
                     div {
                         class: "flex flex-row gap-4",
+                        NotificationsButton {}
                         SettingsButton {}
                         MountWalletAdapter {}
                     }
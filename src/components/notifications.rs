@@ -0,0 +1,157 @@
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::signature::Signature;
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
+
+use crate::{
+    components::{Appearance, BellIcon, Spinner},
+    hooks::{
+        forget_transactions, mark_transactions_seen, use_appearance, use_datetime,
+        use_pending_transactions, PendingTransaction, PendingTransactionStatus,
+    },
+    route::Route,
+};
+
+pub fn NotificationsButton() -> Element {
+    let appearance = use_appearance();
+    let pending_transactions = use_pending_transactions();
+    let mut is_open = use_signal(|| false);
+    let button_color = match *appearance.read() {
+        Appearance::Light => "text-gray-300 hover:text-black",
+        Appearance::Dark => "text-gray-300 hover:text-white",
+    };
+    let has_unseen = pending_transactions
+        .read()
+        .iter()
+        .any(|tx| !tx.seen && tx.status.ne(&PendingTransactionStatus::Pending));
+    let is_pending = pending_transactions
+        .read()
+        .iter()
+        .any(|tx| tx.status.eq(&PendingTransactionStatus::Pending));
+
+    rsx! {
+        div {
+            class: "relative flex my-auto",
+            button {
+                class: "relative flex h-8 w-8 sm:h-10 sm:w-10 transition-colors rounded-full {button_color} hover-100 active-200",
+                onclick: move |_| {
+                    let open = !*is_open.read();
+                    is_open.set(open);
+                    if open {
+                        spawn(mark_transactions_seen(pending_transactions));
+                    }
+                },
+                BellIcon {
+                    class: "h-5 w-5 sm:h-6 sm:w-6 m-auto"
+                }
+                if is_pending {
+                    span {
+                        class: "absolute top-1 right-1 h-2 w-2 rounded-full bg-gray-300 animate-pulse"
+                    }
+                } else if has_unseen {
+                    span {
+                        class: "absolute top-1 right-1 h-2 w-2 rounded-full bg-green-500"
+                    }
+                }
+            }
+            if *is_open.read() {
+                NotificationsPanel {}
+            }
+        }
+    }
+}
+
+fn NotificationsPanel() -> Element {
+    let pending_transactions = use_pending_transactions();
+    let settled: Vec<Signature> = pending_transactions
+        .read()
+        .iter()
+        .filter(|tx| tx.status.ne(&PendingTransactionStatus::Pending))
+        .map(|tx| tx.signature)
+        .collect();
+
+    rsx! {
+        div {
+            class: "absolute right-0 top-12 z-50 flex flex-col w-80 max-h-96 overflow-y-auto rounded shadow bg-white text-black dark:bg-gray-900 dark:text-white",
+            div {
+                class: "flex flex-row justify-between px-4 py-3",
+                p {
+                    class: "font-semibold",
+                    "Transactions"
+                }
+                if !settled.is_empty() {
+                    button {
+                        class: "text-sm text-gray-300 hover:underline",
+                        onclick: move |_| {
+                            let settled = settled.clone();
+                            spawn(async move {
+                                forget_transactions(pending_transactions, &settled).await;
+                            });
+                        },
+                        "Clear"
+                    }
+                }
+            }
+            if pending_transactions.read().is_empty() {
+                p {
+                    class: "px-4 pb-4 text-sm text-gray-300",
+                    "No recent transactions"
+                }
+            }
+            for tx in pending_transactions.read().iter().cloned() {
+                Notification { tx }
+            }
+        }
+    }
+}
+
+#[component]
+fn Notification(tx: PendingTransaction) -> Element {
+    let title = tx.kind.title();
+    let amount = tx
+        .amount
+        .map(|amount| format!("{} ORE", amount_to_ui_amount(amount, tx.kind.decimals())));
+    let time = use_datetime(tx.timestamp);
+    let (status, status_class) = match &tx.status {
+        PendingTransactionStatus::Pending => ("Pending", "text-gray-300"),
+        PendingTransactionStatus::Confirmed => ("Confirmed", "text-green-500"),
+        PendingTransactionStatus::Failed(_) => ("Failed", "text-red-500"),
+        PendingTransactionStatus::Expired => ("Expired", "text-red-500"),
+    };
+
+    rsx! {
+        Link {
+            to: Route::Tx { sig: tx.signature.to_string() },
+            class: "flex flex-col gap-1 px-4 py-3 transition-colors hover-100 active-200",
+            div {
+                class: "flex flex-row justify-between gap-2",
+                p {
+                    class: "font-medium",
+                    "{title}"
+                }
+                div {
+                    class: "flex flex-row gap-2 text-sm font-medium {status_class}",
+                    if tx.status.eq(&PendingTransactionStatus::Pending) {
+                        Spinner { class: "w-4 h-4 my-auto" }
+                    }
+                    "{status}"
+                }
+            }
+            if let Some(amount) = amount {
+                p {
+                    class: "text-sm",
+                    "{amount}"
+                }
+            }
+            if let PendingTransactionStatus::Failed(err) = &tx.status {
+                p {
+                    class: "text-xs text-red-500 break-words",
+                    "{err}"
+                }
+            }
+            p {
+                class: "text-xs text-gray-300",
+                "{time}"
+            }
+        }
+    }
+}
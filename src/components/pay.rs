@@ -1,37 +1,82 @@
 use dioxus::prelude::*;
-use ore_api::consts::MINT_ADDRESS;
-use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Signature};
+use web_time::Duration;
 
 use crate::{
-    components::BackButton,
-    hooks::use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
+    components::{BackButton, CheckCircleIcon},
+    gateway::TransferRequest,
+    hooks::{
+        use_gateway,
+        use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
+    },
+    route::Route,
+    tx::validate_memo,
+    utils::parse_ui_amount,
 };
 
 use super::Copyable;
 
+// How often to check whether the request has been paid
+const REFERENCE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 #[component]
 pub fn Pay() -> Element {
     let nav = use_navigator();
     let wallet_adapter = use_wallet_adapter();
     let mut amount: Signal<Option<String>> = use_signal(|| None);
+    let label = use_signal(|| "ORE".to_string());
+    let message = use_signal(|| "".to_string());
+    let memo = use_signal(|| "".to_string());
+    let mut reference = use_signal(new_reference);
+    let mut paid = use_signal::<Option<Signature>>(|| None);
+    let memo_error_text = validate_memo(memo.read().trim()).err();
 
-    let qrcode = use_resource(move || async move {
-        match *wallet_adapter.read() {
-            WalletAdapter::Disconnected => None,
-            WalletAdapter::Connected(address) => {
-                let solana_pay_req = solana_pay_url(address, amount.cloned());
-                let qrcode = qrcode_generator::to_svg_to_string(
-                    solana_pay_req,
-                    qrcode_generator::QrCodeEcc::Low,
-                    192,
-                    None::<&str>,
-                )
-                .unwrap();
-                Some(qrcode)
+    let request = use_memo(move || match *wallet_adapter.read() {
+        WalletAdapter::Disconnected => None,
+        WalletAdapter::Connected(address) => Some(TransferRequest {
+            recipient: address,
+            amount: amount
+                .cloned()
+                .and_then(|amount| parse_ui_amount(amount.trim(), ore_api::consts::TOKEN_DECIMALS))
+                .filter(|amount| amount.gt(&0)),
+            references: vec![*reference.read()],
+            label: non_empty(&label.read()),
+            message: non_empty(&message.read()),
+            memo: non_empty(&memo.read()).filter(|memo| validate_memo(memo).is_ok()),
+        }),
+    });
+
+    // Watch for a transaction which pays the request
+    use_future(move || async move {
+        loop {
+            async_std::task::sleep(REFERENCE_POLL_INTERVAL).await;
+            if paid.peek().is_some() {
+                continue;
+            }
+            let Some(req) = request.peek().clone() else {
+                continue;
+            };
+            if let Some(sig) = use_gateway().find_transfer(&req).await {
+                // Skip the result if the request was changed while checking
+                if request.peek().as_ref().eq(&Some(&req)) {
+                    paid.set(Some(sig));
+                }
             }
         }
     });
 
+    let qrcode = use_resource(move || async move {
+        let solana_pay_req = request.cloned()?.to_url();
+        let qrcode = qrcode_generator::to_svg_to_string(
+            solana_pay_req,
+            qrcode_generator::QrCodeEcc::Low,
+            192,
+            None::<&str>,
+        )
+        .unwrap();
+        Some(qrcode)
+    });
+
     rsx! {
         div {
             class: "flex flex-col h-full grow justify-between",
@@ -59,7 +104,29 @@ pub fn Pay() -> Element {
             }
             div {
                 class: "flex flex-col gap-8",
-                if let Some(qrcode) = qrcode.cloned() {
+                if let Some(sig) = *paid.read() {
+                    div {
+                        class: "flex flex-col gap-4",
+                        CheckCircleIcon { class: "h-12 w-12 mx-auto" }
+                        p {
+                            class: "text-lg font-semibold text-center",
+                            "Payment received"
+                        }
+                        Link {
+                            class: "font-mono text-nowrap truncate mx-auto w-full p-2 rounded hover-100 active-200 text-center",
+                            to: Route::Tx { sig: sig.to_string() },
+                            "{sig}"
+                        }
+                        button {
+                            class: "mx-auto text-sm font-semibold text-gray-300 hover:underline",
+                            onclick: move |_| {
+                                reference.set(new_reference());
+                                paid.set(None);
+                            },
+                            "New request"
+                        }
+                    }
+                } else if let Some(qrcode) = qrcode.cloned() {
                     if let Some(qrcode) = qrcode {
                         div {
                             class: "flex flex-col gap-8",
@@ -91,6 +158,27 @@ pub fn Pay() -> Element {
                    }
                 }
             }
+            div {
+                class: "flex flex-col gap-2",
+                PayField {
+                    title: "Label",
+                    value: label,
+                }
+                PayField {
+                    title: "Message",
+                    value: message,
+                }
+                PayField {
+                    title: "Memo",
+                    value: memo,
+                }
+                if let Some(err) = memo_error_text {
+                    p {
+                        class: "text-right text-red-500 font-semibold text-sm",
+                        "{err}"
+                    }
+                }
+            }
             div {
                 class: "flex flex-row gap-8 justify-between",
                 p {
@@ -116,16 +204,33 @@ pub fn Pay() -> Element {
     }
 }
 
-fn solana_pay_url(pubkey: Pubkey, amount: Option<String>) -> String {
-    // format!(
-    //     "solana:{}?amount={}&label=Ore&message=Topping%20up%20Ore%20miner",
-    //     pubkey, amount
-    // )
-    match amount {
-        Some(amount) => format!(
-            "solana:{}?&amount={}&spl-token={}&label=ORE",
-            pubkey, amount, MINT_ADDRESS
-        ),
-        None => format!("solana:{}?&spl-token={}&label=ORE", pubkey, MINT_ADDRESS),
+#[component]
+fn PayField(title: String, value: Signal<String>) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-row gap-8 justify-between",
+            p {
+                class: "text-sm font-semibold text-gray-300 font-medium my-auto",
+                "{title}"
+            }
+            input {
+                class: "bg-transparent text-right h-10 px-1 font-semibold outline-none placeholder-gray-200 dark:placeholder-gray-700",
+                placeholder: "Optional",
+                value: "{value}",
+                oninput: move |e| {
+                    value.set(e.value());
+                }
+            }
+        }
     }
 }
+
+// Each request gets a fresh reference, so its payment can't be confused with another's
+fn new_reference() -> Pubkey {
+    Pubkey::new_from_array(rand::random())
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
@@ -2,87 +2,71 @@ use std::borrow::BorrowMut;
 
 use dioxus::prelude::*;
 use solana_client_wasm::solana_sdk::pubkey::Pubkey;
-use solana_extra_wasm::program::{
-    spl_associated_token_account::instruction::create_associated_token_account,
-    spl_token::{self, amount_to_ui_amount},
-};
-use solana_sdk::{compute_budget::ComputeBudgetInstruction, transaction::Transaction};
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 
 use crate::{
-    components::{BackButton, InvokeSignature, OreIcon},
-    gateway::{self, ore_token_account_address},
+    components::{BackButton, ErrorMessage, InvokeSignature, OreIcon},
+    gateway::{FeeAction, GatewayError, TransferRequest},
     hooks::{
-        use_gateway, use_ore_balance,
+        use_gateway, use_lookup_table, use_priority_fee,
         use_wallet_adapter::{use_wallet_adapter, InvokeSignatureStatus, WalletAdapter},
+        TransactionKind,
     },
+    tx,
 };
 
-use super::SendStep;
+use super::{PaymentRequestInfo, SendStep};
 
 #[component]
 pub fn SendConfirm(
     send_step: Signal<SendStep>,
     amount: u64,
     recipient: Pubkey,
-    memo: String,
+    memo: Option<String>,
+    transfer_request: Option<TransferRequest>,
 ) -> Element {
-    let mut ore_balance = use_ore_balance();
     let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
     let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
 
+    let tx_memo = memo.clone();
+    let tx_references = transfer_request
+        .as_ref()
+        .map(|request| request.references.clone());
     let tx = use_resource(move || {
+        let memo = tx_memo.clone();
+        let references = tx_references.clone();
         async move {
             if let WalletAdapter::Connected(signer) = *wallet_adapter.read() {
-                // Cu limit
-                let price = gateway::get_recent_priority_fee_estimate(false).await;
-                let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(30_000);
-                let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                let mut ixs = vec![cu_limit_ix, cu_price_ix];
-                let from_token_account = ore_token_account_address(signer);
-                let to_token_account = ore_token_account_address(recipient);
-
-                // Add create ata ix
                 let gateway = use_gateway();
-                if let Ok(Some(_)) = gateway.get_token_account(&to_token_account).await {
-                } else {
-                    ixs.remove(0);
-                    ixs.insert(0, ComputeBudgetInstruction::set_compute_unit_limit(125_000));
-                    ixs.push(create_associated_token_account(
-                        &signer,
-                        &recipient,
-                        &ore_api::consts::MINT_ADDRESS,
-                        &spl_token::id(),
-                    ));
-                }
-
-                // Add transfer
-                ixs.push(
-                    spl_token::instruction::transfer(
-                        &spl_token::ID,
-                        &from_token_account,
-                        &to_token_account,
-                        &signer,
-                        &[&signer],
-                        amount,
-                    )
-                    .unwrap(),
-                );
-
-                // Add memo
-                // ixs.push(spl_memo::build_memo(&memo_bytes, &[&signer]));
-
-                // Return tx
-                let mut tx = Transaction::new_with_payer(&ixs, Some(&signer));
-                tx.message.recent_blockhash = gateway.rpc.get_latest_blockhash().await.unwrap();
-                Some(tx)
+                let price = gateway
+                    .get_priority_fee(*priority_fee.read(), FeeAction::Send)
+                    .await;
+                // Payment requests are paid as the spec lays out, so the recipient can find them
+                let builder = match references {
+                    Some(references) => {
+                        tx::pay(signer, recipient, amount, &references, memo.as_deref())
+                    }
+                    None => {
+                        let mut builder = tx::send(signer, recipient, amount);
+                        if let Some(memo) = memo {
+                            builder = builder.memo(&memo);
+                        }
+                        builder
+                    }
+                };
+                let builder = builder.priority_fee(price);
+                gateway
+                    .build_transaction(builder, lookup_table.read().0)
+                    .await
             } else {
-                None
+                Err(GatewayError::WalletAdapterDisconnected)
             }
         }
     });
 
     if let InvokeSignatureStatus::Done(_sig) = *invoke_signature_signal.read() {
-        ore_balance.restart();
         send_step.set(SendStep::Done);
     };
 
@@ -110,6 +94,12 @@ pub fn SendConfirm(
             }
             div {
                 class: "flex flex-col gap-8",
+                if let Some(request) = transfer_request {
+                    PaymentRequestInfo {
+                        label: request.label,
+                        message: request.message,
+                    }
+                }
                 div {
                     class: "flex flex-col gap-2",
                     p {
@@ -136,19 +126,23 @@ pub fn SendConfirm(
                         "{recipient.to_string()}"
                     }
                 }
-                div {
-                    class: "flex flex-col gap-2",
-                    p {
-                        "Memo"
-                    }
-                    p {
-                        class: "text-2xl",
-                        "{memo}"
+                if let Some(memo) = memo {
+                    div {
+                        class: "flex flex-col gap-2",
+                        p {
+                            "Memo"
+                        }
+                        p {
+                            class: "text-2xl break-words",
+                            "{memo}"
+                        }
                     }
                 }
             }
-            if let Some(Some(tx)) = tx.cloned() {
-                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm" }
+            if let Some(Ok(tx)) = tx.cloned() {
+                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm", kind: TransactionKind::Send, amount: amount }
+            } else if let Some(Err(err)) = tx.cloned() {
+                ErrorMessage { err }
             } else {
                 p {
                     class: "font-medium text-center text-sm text-gray-300 hover:underline",
@@ -5,13 +5,15 @@ use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 
 use crate::{
     components::{BackButton, WarningIcon},
-    hooks::{use_ore_balance, UiTokenAmountBalance},
+    gateway::{parse_payment_url, PaymentRequest},
+    hooks::{address_name, use_address_book, use_ore_balance, UiTokenAmountBalance},
+    route::Route,
+    tx::validate_memo,
 };
 
-use super::SendStep;
+use super::{apply_payment_request, SendStep};
 
 // TODO Break recipient, amount, and memo into sequential fullscreen steps
-// TODO Max size on memo
 
 #[component]
 pub fn SendEdit(
@@ -19,11 +21,14 @@ pub fn SendEdit(
     amount_input: Signal<String>,
     recipient_input: Signal<String>,
     memo_input: Signal<String>,
+    pay_request: Signal<Option<PaymentRequest>>,
     parsed_amount: u64,
 ) -> Element {
     let nav = navigator();
     let balance = use_ore_balance();
+    let address_book = use_address_book();
     let recipient = Pubkey::from_str(recipient_input.read().as_str());
+    let recipient_name = address_name(&address_book.read(), recipient_input.read().as_str());
     let (max_amount, max_amount_str) = balance
         .cloned()
         .and_then(|b| b.ok())
@@ -37,19 +42,27 @@ pub fn SendEdit(
     let recipient_error_text = match recipient {
         Ok(_) => None,
         Err(_) => {
-            if recipient_input.read().len().gt(&0) {
+            if recipient_input.read().starts_with("solana:") {
+                parse_payment_url(&recipient_input.read()).err()
+            } else if recipient_input.read().len().gt(&0) {
                 Some("Invalid address".to_string())
             } else {
                 None
             }
         }
     };
+    let transfer_request = match pay_request.cloned() {
+        Some(PaymentRequest::Transfer(request)) => Some(request),
+        _ => None,
+    };
+
+    let memo_error_text = validate_memo(memo_input.read().trim()).err();
 
     let is_disabled = amount_input.read().len().eq(&0)
         || amount_input.read().parse::<f64>().is_err()
         || amount_error_text.is_some()
         || recipient.is_err()
-        || memo_input.read().trim().len().eq(&0);
+        || memo_error_text.is_some();
 
     rsx! {
         div {
@@ -74,6 +87,11 @@ pub fn SendEdit(
                         class: "text-sm text-gray-300",
                         "This transaction will be executed and settled on the Solana blockchain."
                     }
+                    Link {
+                        class: "text-sm font-semibold text-gray-300 hover:underline w-min text-nowrap",
+                        to: Route::SendBatch {},
+                        "Sending to many recipients? Batch send"
+                    }
                 }
             }
             div {
@@ -87,12 +105,50 @@ pub fn SendEdit(
                     input {
                         class: "mx-auto w-full focus:ring-0 outline-none placeholder-gray-200 dark:placeholder-gray-700 bg-transparent text-xl",
                         autofocus: recipient_input.read().eq(&""),
-                        placeholder: "Address",
+                        placeholder: "Address or Solana Pay url",
+                        list: "address-book",
                         value: "{*recipient_input.read()}",
                         oninput: move |e| {
-                            recipient_input.set(e.value());
+                            let value = e.value();
+                            if let Ok(request) = parse_payment_url(&value) {
+                                apply_payment_request(request, recipient_input, amount_input, memo_input, pay_request);
+                                return;
+                            }
+                            // A request's references only apply to its own recipient
+                            let is_request_recipient = pay_request
+                                .cloned()
+                                .map(|request| match request {
+                                    PaymentRequest::Transfer(request) => request.recipient.to_string().eq(&value),
+                                    PaymentRequest::Transaction(_) => false,
+                                })
+                                .unwrap_or(false);
+                            if !is_request_recipient {
+                                pay_request.set(None);
+                            }
+                            recipient_input.set(value);
                         },
                     }
+                    datalist {
+                        id: "address-book",
+                        for contact in address_book.read().0.iter() {
+                            option {
+                                value: "{contact.address}",
+                                "{contact.label}"
+                            }
+                        }
+                    }
+                    if let Some(request) = transfer_request {
+                        PaymentRequestInfo {
+                            label: request.label,
+                            message: request.message,
+                        }
+                    }
+                    if let Some(name) = recipient_name {
+                        p {
+                            class: "font-semibold text-sm text-gray-300",
+                            "{name}"
+                        }
+                    }
                     if let Some(err) = recipient_error_text {
                         p {
                             class: "flex flex-row flex-nowrap gap-1.5 w-min text-nowrap text-red-500 font-semibold text-sm",
@@ -151,11 +207,21 @@ pub fn SendEdit(
                     }
                     input {
                         class: "mx-auto w-full focus:ring-0 outline-none placeholder-gray-200 dark:placeholder-gray-700 bg-transparent text-xl",
-                        placeholder: "What's this for?",
+                        placeholder: "What's this for? (optional)",
+                        value: "{memo_input}",
                         oninput: move |e| {
                             memo_input.set(e.value());
                         },
                     }
+                    if let Some(err) = memo_error_text {
+                        p {
+                            class: "flex flex-row flex-nowrap gap-1.5 w-min text-nowrap text-red-500 font-semibold text-sm",
+                            WarningIcon {
+                                class: "w-4 h-4 my-auto"
+                            }
+                            "{err}"
+                        }
+                    }
                 }
             }
             div {
@@ -172,3 +238,23 @@ pub fn SendEdit(
         }
     }
 }
+
+#[component]
+pub fn PaymentRequestInfo(label: Option<String>, message: Option<String>) -> Element {
+    let label = label.unwrap_or("Payment request".to_string());
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 text-sm",
+            p {
+                class: "font-semibold",
+                "{label}"
+            }
+            if let Some(message) = message {
+                p {
+                    class: "text-gray-300",
+                    "{message}"
+                }
+            }
+        }
+    }
+}
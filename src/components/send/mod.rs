@@ -1,15 +1,20 @@
 mod confirm;
 mod done;
 mod edit;
+mod request;
 
 use confirm::*;
 use done::*;
 use edit::*;
+use request::*;
 
 use std::str::FromStr;
 
 use dioxus::prelude::*;
 use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
+
+use crate::gateway::{parse_payment_url, PaymentRequest};
 
 pub enum SendStep {
     Edit,
@@ -19,17 +24,59 @@ pub enum SendStep {
 
 #[component]
 pub fn Send(to: Option<String>) -> Element {
+    // Links to send can carry a Solana Pay url in place of the recipient
+    let initial_request = use_hook(|| {
+        to.as_deref()
+            .filter(|to| to.starts_with("solana:"))
+            .and_then(|to| parse_payment_url(to).ok())
+    });
+    let initial_transfer = match &initial_request {
+        Some(PaymentRequest::Transfer(request)) => Some(request.clone()),
+        _ => None,
+    };
+
     let send_step = use_signal(|| SendStep::Edit);
-    let amount_input = use_signal(|| "".to_string());
-    let memo_input = use_signal(|| "".to_string());
+    let amount_input = use_signal(|| {
+        initial_transfer
+            .as_ref()
+            .and_then(|request| request.amount)
+            .map(ui_amount_string)
+            .unwrap_or_default()
+    });
+    let memo_input = use_signal(|| {
+        initial_transfer
+            .as_ref()
+            .and_then(|request| request.memo.clone())
+            .unwrap_or_default()
+    });
+    let recipient_input = use_signal(|| match (&initial_request, &initial_transfer) {
+        (_, Some(request)) => request.recipient.to_string(),
+        (Some(_), None) => "".to_string(),
+        (None, None) => to.clone().unwrap_or("".to_string()),
+    });
+    let pay_request = use_signal(|| initial_request);
+
     let memo = memo_input.read().trim().to_string();
-    let recipient_input = use_signal(|| to.clone().unwrap_or("".to_string()));
+    let memo = (!memo.is_empty()).then_some(memo);
     let recipient = Pubkey::from_str(&recipient_input.read()).ok();
     let parsed_amount: u64 = match amount_input.read().parse::<f64>() {
         Ok(n) => (n * 10f64.powf(ore_api::consts::TOKEN_DECIMALS.into())) as u64,
         Err(_) => 0,
     };
 
+    if let Some(PaymentRequest::Transaction(request)) = pay_request.cloned() {
+        return rsx! {
+            SendRequest {
+                request: request,
+                pay_request: pay_request,
+            }
+        };
+    }
+    let transfer_request = match pay_request.cloned() {
+        Some(PaymentRequest::Transfer(request)) => Some(request),
+        _ => None,
+    };
+
     let e = match *send_step.read() {
         SendStep::Edit => {
             rsx! {
@@ -38,7 +85,8 @@ pub fn Send(to: Option<String>) -> Element {
                     parsed_amount: parsed_amount,
                     amount_input: amount_input,
                     recipient_input: recipient_input,
-                    memo_input: memo_input
+                    memo_input: memo_input,
+                    pay_request: pay_request,
                 }
             }
         }
@@ -49,6 +97,7 @@ pub fn Send(to: Option<String>) -> Element {
                     amount: parsed_amount,
                     memo: memo,
                     recipient: recipient.unwrap(),
+                    transfer_request: transfer_request,
                 }
             }
         }
@@ -61,3 +110,24 @@ pub fn Send(to: Option<String>) -> Element {
 
     e
 }
+
+/// Fills in the send form from a Solana Pay request. Transaction requests are built by the
+/// merchant, so they have no form to fill.
+pub fn apply_payment_request(
+    request: PaymentRequest,
+    mut recipient_input: Signal<String>,
+    mut amount_input: Signal<String>,
+    mut memo_input: Signal<String>,
+    mut pay_request: Signal<Option<PaymentRequest>>,
+) {
+    if let PaymentRequest::Transfer(transfer) = &request {
+        recipient_input.set(transfer.recipient.to_string());
+        amount_input.set(transfer.amount.map(ui_amount_string).unwrap_or_default());
+        memo_input.set(transfer.memo.clone().unwrap_or_default());
+    }
+    pay_request.set(Some(request));
+}
+
+fn ui_amount_string(amount: u64) -> String {
+    amount_to_ui_amount(amount, ore_api::consts::TOKEN_DECIMALS).to_string()
+}
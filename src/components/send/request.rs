@@ -0,0 +1,163 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::{BackButton, ErrorMessage, InstructionRow, InvokeSignature},
+    gateway::{GatewayError, PaymentRequest, TransactionRequest},
+    hooks::{
+        use_gateway,
+        use_wallet_adapter::{use_wallet_adapter, InvokeSignatureStatus, WalletAdapter},
+        TransactionKind,
+    },
+};
+
+use super::SendDone;
+
+/// Signs a transaction built by a merchant for a Solana Pay transaction request.
+#[component]
+pub fn SendRequest(
+    request: TransactionRequest,
+    pay_request: Signal<Option<PaymentRequest>>,
+) -> Element {
+    let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
+    let wallet_adapter = use_wallet_adapter();
+    let host = url::Url::parse(&request.link)
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+        .unwrap_or_default();
+
+    let info_request = request.clone();
+    let info = use_resource(move || {
+        let request = info_request.clone();
+        async move { use_gateway().get_transaction_request(&request).await }
+    });
+
+    let tx_request = request.clone();
+    let tx = use_resource(move || {
+        let request = tx_request.clone();
+        async move {
+            if let WalletAdapter::Connected(account) = *wallet_adapter.read() {
+                use_gateway()
+                    .post_transaction_request(&request, account)
+                    .await
+            } else {
+                Err(GatewayError::WalletAdapterDisconnected)
+            }
+        }
+    });
+
+    // Decode and simulate the merchant's transaction, so it can be reviewed before signing
+    let review = use_resource(move || async move {
+        let Some(Ok((tx, _))) = tx.cloned() else {
+            return None;
+        };
+        let gateway = use_gateway();
+        let (instructions, simulation) =
+            futures::join!(gateway.decode_transaction(&tx), gateway.simulate(&tx));
+        Some((instructions, simulation))
+    });
+
+    if let InvokeSignatureStatus::Done(_sig) = *invoke_signature_signal.read() {
+        return rsx! {
+            SendDone {}
+        };
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-col h-full grow gap-12",
+            div {
+                class: "flex flex-col gap-2",
+                BackButton {
+                    onclick: move |_| {
+                        pay_request.set(None);
+                    }
+                }
+                h2 {
+                    "Confirm"
+                }
+                p {
+                    class: "text-lg",
+                    "Please review the payment request for correctness."
+                }
+                p {
+                    class: "text-sm text-gray-300",
+                    "The transaction was built by {host}. Once confirmed, it cannot be undone."
+                }
+            }
+            div {
+                class: "flex flex-col gap-8",
+                if let Some(Ok(info)) = info.cloned() {
+                    div {
+                        class: "flex flex-row gap-4",
+                        img {
+                            class: "w-12 h-12 rounded-full my-auto",
+                            src: "{info.icon}",
+                        }
+                        p {
+                            class: "text-2xl my-auto",
+                            "{info.label}"
+                        }
+                    }
+                }
+                if let Some(Ok((_, Some(message)))) = tx.cloned() {
+                    div {
+                        class: "flex flex-col gap-2",
+                        p {
+                            "Message"
+                        }
+                        p {
+                            class: "text-2xl break-words",
+                            "{message}"
+                        }
+                    }
+                }
+                if let Some(Some((instructions, simulation))) = review.cloned() {
+                    div {
+                        class: "flex flex-col gap-2",
+                        p {
+                            "Instructions"
+                        }
+                        for (i, ix) in instructions.into_iter().enumerate() {
+                            InstructionRow {
+                                key: "{i}",
+                                ix
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex flex-col gap-2",
+                        p {
+                            "Simulation"
+                        }
+                        match simulation {
+                            Ok(simulation) => rsx! {
+                                p {
+                                    class: "text-sm text-green-500",
+                                    "Succeeds using {simulation.units_consumed} compute units"
+                                }
+                            },
+                            Err(err) => rsx! {
+                                ErrorMessage { err }
+                            },
+                        }
+                    }
+                }
+            }
+            if let Some(Err(err)) = tx.cloned() {
+                ErrorMessage { err }
+            } else if let (Some(Ok((tx, _))), Some(Some((_, Ok(_))))) = (tx.cloned(), review.cloned()) {
+                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm", kind: TransactionKind::Send }
+            } else if let Some(Some((_, Err(_)))) = review.cloned() {
+                p {
+                    class: "font-medium text-center text-sm text-gray-300",
+                    "The transaction would fail, so it can't be signed."
+                }
+            } else {
+                p {
+                    class: "font-medium text-center text-sm text-gray-300 hover:underline",
+                    "Loading..."
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use dioxus::prelude::*;
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
+
+use crate::{
+    components::{BackButton, ErrorMessage, Spinner},
+    gateway::{FeeAction, Gateway, GatewayError, GatewayResult, PriorityFee, SignatureStatus},
+    hooks::{
+        settle_transaction, track_transaction, use_gateway, use_lookup_table,
+        use_pending_transactions, use_priority_fee,
+        use_wallet_adapter::{sign_transaction, use_wallet_adapter, WalletAdapter},
+        PendingTransaction, TransactionKind,
+    },
+    route::Route,
+    tx::{self, BatchTransfer, TxBuilder},
+};
+
+use super::SendBatchStep;
+
+#[derive(Clone, Debug, PartialEq)]
+enum BatchRowStatus {
+    Queued,
+    Sending,
+    Confirmed(Signature),
+    Failed(String),
+    /// The transaction expired before it confirmed, but may still have landed.
+    Expired(Signature),
+}
+
+#[component]
+pub fn SendBatchConfirm(
+    send_batch_step: Signal<SendBatchStep>,
+    transfers: Vec<BatchTransfer>,
+) -> Element {
+    let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
+    let pending_transactions = use_pending_transactions();
+    let statuses = use_signal(|| vec![BatchRowStatus::Queued; transfers.len()]);
+    let is_sending = use_signal(|| false);
+
+    let WalletAdapter::Connected(signer) = *wallet_adapter.read() else {
+        return rsx! {
+            ErrorMessage {
+                err: GatewayError::WalletAdapterDisconnected
+            }
+        };
+    };
+
+    let total: u64 = transfers.iter().map(|transfer| transfer.amount).sum();
+    let tx_count = tx::pack_transfers(signer, &transfers, 0)
+        .iter()
+        .filter(|(_, builder)| builder.is_ok())
+        .count();
+    let batch = transfers.clone();
+    let is_started = statuses
+        .read()
+        .iter()
+        .any(|status| status.ne(&BatchRowStatus::Queued));
+    let is_done = statuses
+        .read()
+        .iter()
+        .all(|status| matches!(status, BatchRowStatus::Confirmed(_)));
+
+    rsx! {
+        div {
+            class: "flex flex-col h-full grow gap-12",
+            div {
+                class: "flex flex-col gap-2",
+                if !is_started {
+                    BackButton {
+                        onclick: move |_| {
+                            send_batch_step.set(SendBatchStep::Edit);
+                        }
+                    }
+                }
+                h2 {
+                    "Confirm"
+                }
+                p {
+                    class: "text-lg",
+                    "Please review your transfers for correctness."
+                }
+                p {
+                    class: "text-sm text-gray-300",
+                    "{transfers.len()} transfers of {amount_to_ui_amount(total, ore_api::consts::TOKEN_DECIMALS)} ORE in total, packed into {tx_count} transactions. Each transaction asks for your signature in turn."
+                }
+            }
+            div {
+                class: "flex flex-col gap-2",
+                for (i, transfer) in transfers.iter().enumerate() {
+                    BatchRow {
+                        key: "{i}",
+                        transfer: transfer.clone(),
+                        status: statuses.read()[i].clone(),
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-3 mt-auto",
+                if is_done {
+                    Link {
+                        class: "w-full py-3 rounded font-semibold transition-colors text-center text-white bg-green-500 hover:bg-green-600 active:bg-green-700",
+                        to: Route::Home {},
+                        "Done"
+                    }
+                } else {
+                    button {
+                        class: "w-full py-3 rounded font-semibold transition-colors transition-opacity text-white bg-green-500 hover:bg-green-600 active:bg-green-700 disabled:opacity-20",
+                        disabled: *is_sending.read(),
+                        onclick: move |_| {
+                            send_transfers(
+                                signer,
+                                batch.clone(),
+                                statuses,
+                                is_sending,
+                                pending_transactions,
+                                *priority_fee.read(),
+                                lookup_table.read().0,
+                            );
+                        },
+                        if *is_sending.read() {
+                            "Sending..."
+                        } else if is_started {
+                            "Retry failed"
+                        } else {
+                            "Confirm"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BatchRow(transfer: BatchTransfer, status: BatchRowStatus) -> Element {
+    let recipient = transfer.recipient.to_string();
+    let memo = transfer.memo.clone().unwrap_or_default();
+    let (label, class) = match &status {
+        BatchRowStatus::Queued => ("Queued", "text-gray-300"),
+        BatchRowStatus::Sending => ("Sending", "text-gray-300"),
+        BatchRowStatus::Confirmed(_) => ("Confirmed", "text-green-500"),
+        BatchRowStatus::Failed(_) => ("Failed", "text-red-500"),
+        BatchRowStatus::Expired(_) => ("Expired", "text-red-500"),
+    };
+    let sig = match &status {
+        BatchRowStatus::Confirmed(sig) | BatchRowStatus::Expired(sig) => Some(*sig),
+        _ => None,
+    };
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 py-2 sm:px-1",
+            div {
+                class: "flex flex-row justify-between gap-4",
+                p {
+                    class: "font-mono truncate",
+                    "{recipient}"
+                }
+                p {
+                    class: "font-medium text-nowrap",
+                    "{amount_to_ui_amount(transfer.amount, ore_api::consts::TOKEN_DECIMALS)} ORE"
+                }
+            }
+            div {
+                class: "flex flex-row justify-between gap-4 text-sm",
+                p {
+                    class: "text-gray-300 truncate",
+                    "{memo}"
+                }
+                if let Some(sig) = sig {
+                    Link {
+                        class: "font-medium text-nowrap hover:underline {class}",
+                        to: Route::Tx { sig: sig.to_string() },
+                        "{label}"
+                    }
+                } else {
+                    div {
+                        class: "flex flex-row gap-2 font-medium text-nowrap {class}",
+                        if status.eq(&BatchRowStatus::Sending) {
+                            Spinner { class: "w-4 h-4 my-auto" }
+                        }
+                        "{label}"
+                    }
+                }
+            }
+            if let BatchRowStatus::Failed(err) = &status {
+                p {
+                    class: "text-xs text-red-500 break-words",
+                    "{err}"
+                }
+            }
+        }
+    }
+}
+
+// Sends the transfers which haven't confirmed, packed into as few transactions as fit and
+// signed one after another. Expired transactions are checked again first, so a transfer
+// which landed late isn't sent twice. A denied signature stops the batch.
+fn send_transfers(
+    signer: Pubkey,
+    transfers: Vec<BatchTransfer>,
+    mut statuses: Signal<Vec<BatchRowStatus>>,
+    mut is_sending: Signal<bool>,
+    pending_transactions: Signal<Vec<PendingTransaction>>,
+    priority_fee: PriorityFee,
+    lookup_table: Option<Pubkey>,
+) {
+    is_sending.set(true);
+    spawn(async move {
+        let gateway = use_gateway();
+
+        // Check whether expired transactions landed after all
+        let expired: Vec<Signature> = statuses
+            .read()
+            .iter()
+            .filter_map(|status| match status {
+                BatchRowStatus::Expired(sig) => Some(*sig),
+                _ => None,
+            })
+            .collect();
+        for sig in expired {
+            let status = match gateway.get_signature_status(sig).await {
+                Ok(SignatureStatus::Confirmed) => BatchRowStatus::Confirmed(sig),
+                Ok(SignatureStatus::Failed(err)) => BatchRowStatus::Failed(err.to_string()),
+                // Still unknown, so the blockhash expired without it landing
+                Ok(SignatureStatus::Pending) => BatchRowStatus::Queued,
+                Err(err) => {
+                    log::error!("Failed to check signature {}: {}", sig, err);
+                    continue;
+                }
+            };
+            for row in statuses.write().iter_mut() {
+                if row.eq(&BatchRowStatus::Expired(sig)) {
+                    *row = status.clone();
+                }
+            }
+        }
+
+        let rows: Vec<usize> = statuses
+            .read()
+            .iter()
+            .enumerate()
+            .filter(|(_, status)| {
+                matches!(status, BatchRowStatus::Queued | BatchRowStatus::Failed(_))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let batch: Vec<BatchTransfer> = rows.iter().map(|i| transfers[*i].clone()).collect();
+        let price = gateway
+            .get_priority_fee(priority_fee, FeeAction::Send)
+            .await;
+        for (indices, builder) in tx::pack_transfers(signer, &batch, price) {
+            let group: Vec<usize> = indices.iter().map(|i| rows[*i]).collect();
+            let builder = match builder {
+                Ok(builder) => builder,
+                Err(err) => {
+                    set_statuses(statuses, &group, BatchRowStatus::Failed(err));
+                    continue;
+                }
+            };
+            let amount = indices.iter().map(|i| batch[*i].amount).sum();
+            set_statuses(statuses, &group, BatchRowStatus::Sending);
+            let res = send_group(
+                &gateway,
+                pending_transactions,
+                builder,
+                lookup_table,
+                amount,
+            )
+            .await;
+            let status = match res {
+                Ok(sig) => BatchRowStatus::Confirmed(sig),
+                Err(GatewayError::TransactionExpired(sig)) => BatchRowStatus::Expired(sig),
+                Err(GatewayError::SignatureDenied) => {
+                    set_statuses(statuses, &group, BatchRowStatus::Queued);
+                    break;
+                }
+                Err(err) => BatchRowStatus::Failed(err.to_string()),
+            };
+            set_statuses(statuses, &group, status);
+        }
+        is_sending.set(false);
+    });
+}
+
+async fn send_group(
+    gateway: &Gateway,
+    pending_transactions: Signal<Vec<PendingTransaction>>,
+    builder: TxBuilder,
+    lookup_table: Option<Pubkey>,
+    amount: u64,
+) -> GatewayResult<Signature> {
+    let tx = gateway.build_transaction(builder, lookup_table).await?;
+    let tx = sign_transaction(&tx).await?;
+    track_transaction(
+        pending_transactions,
+        &tx,
+        TransactionKind::SendBatch,
+        Some(amount),
+    )
+    .await;
+    let res = gateway.send_and_confirm(&tx).await;
+    settle_transaction(pending_transactions, tx.signatures[0], &res).await;
+    if res.is_ok() {
+        gateway.invalidate(&gateway.account_keys(&tx.message).await);
+    }
+    res
+}
+
+fn set_statuses(mut statuses: Signal<Vec<BatchRowStatus>>, rows: &[usize], status: BatchRowStatus) {
+    let mut statuses = statuses.write();
+    for i in rows {
+        statuses[*i] = status.clone();
+    }
+}
@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
+
+use crate::{
+    components::{BackButton, WarningIcon},
+    hooks::{use_ore_balance, UiTokenAmountBalance},
+    tx::parse_batch_csv,
+};
+
+use super::SendBatchStep;
+
+#[component]
+pub fn SendBatchEdit(send_batch_step: Signal<SendBatchStep>, csv_input: Signal<String>) -> Element {
+    let nav = navigator();
+    let balance = use_ore_balance();
+    let max_amount = balance
+        .cloned()
+        .and_then(|b| b.ok())
+        .map(|b| b.balance())
+        .unwrap_or(0);
+
+    let rows = parse_batch_csv(&csv_input.read());
+    let mut errors: Vec<String> = rows
+        .iter()
+        .filter_map(|(line, row)| {
+            row.as_ref()
+                .err()
+                .map(|err| format!("Line {}: {}", line, err))
+        })
+        .collect();
+    let total: u64 = rows
+        .iter()
+        .filter_map(|(_, row)| row.as_ref().ok())
+        .map(|transfer| transfer.amount)
+        .sum();
+    if total.gt(&max_amount) {
+        errors.push(format!(
+            "Total of {} ORE is more than your balance",
+            amount_to_ui_amount(total, ore_api::consts::TOKEN_DECIMALS)
+        ));
+    }
+    let count = rows.len();
+    let is_disabled = rows.is_empty() || !errors.is_empty();
+
+    rsx! {
+        div {
+            class: "flex flex-col h-full grow gap-12",
+            div {
+                class: "flex flex-col gap-4 -mt-3.5 mb-4",
+                BackButton {
+                    onclick: move |_| {
+                        nav.go_back()
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-2",
+                    h2 {
+                        "Batch send"
+                    }
+                    p {
+                        class: "text-lg",
+                        "Send ORE to many recipients at once."
+                    }
+                    p {
+                        class: "text-sm text-gray-300",
+                        "Paste or upload a csv with one address,amount,memo row per recipient. Memos are optional."
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col gap-3",
+                div {
+                    class: "flex flex-row justify-between",
+                    p {
+                        class: "font-semibold text-sm",
+                        "Recipients"
+                    }
+                    label {
+                        class: "font-semibold text-sm text-gray-300 hover:underline cursor-pointer",
+                        "Upload csv"
+                        input {
+                            class: "hidden",
+                            r#type: "file",
+                            accept: ".csv,text/csv",
+                            onchange: move |e| async move {
+                                if let Some(file_engine) = e.files() {
+                                    if let Some(name) = file_engine.files().first() {
+                                        if let Some(csv) = file_engine.read_file_to_string(name).await {
+                                            csv_input.set(csv);
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+                textarea {
+                    class: "w-full h-48 p-2 rounded font-mono text-sm focus:ring-0 outline-none placeholder-gray-200 dark:placeholder-gray-700 bg-transparent border border-gray-100 dark:border-gray-800",
+                    placeholder: "address,amount,memo",
+                    value: "{csv_input}",
+                    oninput: move |e| {
+                        csv_input.set(e.value());
+                    },
+                }
+                if count.gt(&0) {
+                    p {
+                        class: "text-sm text-gray-300",
+                        "{count} recipients · {amount_to_ui_amount(total, ore_api::consts::TOKEN_DECIMALS)} ORE"
+                    }
+                }
+                for err in errors {
+                    p {
+                        class: "flex flex-row flex-nowrap gap-1.5 text-red-500 font-semibold text-sm",
+                        WarningIcon {
+                            class: "w-4 h-4 my-auto shrink-0"
+                        }
+                        "{err}"
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col sm:flex-row gap-2 mt-auto",
+                button {
+                    class: "w-full py-3 rounded font-semibold transition-colors transition-opacity text-white bg-green-500 hover:bg-green-600 active:bg-green-700 disabled:opacity-20",
+                    disabled: is_disabled,
+                    onclick: move |_| {
+                        send_batch_step.set(SendBatchStep::Confirm);
+                    },
+                    "Review"
+                }
+            }
+        }
+    }
+}
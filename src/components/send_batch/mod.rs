@@ -0,0 +1,44 @@
+mod confirm;
+mod edit;
+
+use confirm::*;
+use edit::*;
+
+use dioxus::prelude::*;
+
+use crate::tx::{parse_batch_csv, BatchTransfer};
+
+pub enum SendBatchStep {
+    Edit,
+    Confirm,
+}
+
+pub fn SendBatch() -> Element {
+    let send_batch_step = use_signal(|| SendBatchStep::Edit);
+    let csv_input = use_signal(|| "".to_string());
+    let transfers: Vec<BatchTransfer> = parse_batch_csv(&csv_input.read())
+        .into_iter()
+        .filter_map(|(_, row)| row.ok())
+        .collect();
+
+    let e = match *send_batch_step.read() {
+        SendBatchStep::Edit => {
+            rsx! {
+                SendBatchEdit {
+                    send_batch_step: send_batch_step,
+                    csv_input: csv_input,
+                }
+            }
+        }
+        SendBatchStep::Confirm => {
+            rsx! {
+                SendBatchConfirm {
+                    send_batch_step: send_batch_step,
+                    transfers: transfers,
+                }
+            }
+        }
+    };
+
+    e
+}
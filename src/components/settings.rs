@@ -1,20 +1,29 @@
 use std::str::FromStr;
 
 use dioxus::prelude::*;
+use is_url::is_url;
 use solana_client_wasm::solana_sdk::native_token::lamports_to_sol;
+use web_time::Duration;
 
 use crate::{
-    components::Appearance,
+    components::{
+        AddressBookSettings, Appearance, CheckCircleIcon, ErrorMessage, InvokeSignature, Spinner,
+        WarningIcon,
+    },
+    gateway::{FeeAction, FeeLevel, Gateway, GatewayError, PriorityFee},
     hooks::{
-        use_appearance, use_explorer, use_sol_balance,
-        use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
-        Explorer,
+        use_appearance, use_cluster, use_endpoints, use_explorer, use_gateway, use_lookup_table,
+        use_priority_fee, use_slot, use_sol_balance,
+        use_wallet_adapter::{use_wallet_adapter, InvokeSignatureStatus, WalletAdapter},
+        Cluster, Endpoints, Explorer, LookupTable,
     },
+    tx,
 };
 
 pub fn Settings() -> Element {
     let mut explorer = use_explorer();
     let mut appearance = use_appearance();
+    let mut priority_fee = use_priority_fee();
     let sol_balance = use_sol_balance();
     let wallet_adapter = use_wallet_adapter();
 
@@ -58,7 +67,7 @@ pub fn Settings() -> Element {
                             class: "{data_title_class}",
                             "Balance"
                         }
-                        if let Some(balance) = *sol_balance.read() {
+                        if let Some(balance) = sol_balance.cloned() {
                             if let Ok(balance) = balance {
                                 p {
                                     "{lamports_to_sol(balance)} SOL"
@@ -119,6 +128,465 @@ pub fn Settings() -> Element {
                     }
                 }
             }
+            div {
+                class: "flex flex-col gap-4",
+                h2 {
+                    class: "{section_title_class}",
+                    "Transactions"
+                }
+                div {
+                    class: "{container_class}",
+                    p {
+                        class: "{data_title_class}",
+                        "Priority fee"
+                    }
+                    select {
+                        class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                        onchange: move |e| {
+                            if let Ok(p) = PriorityFee::from_str(&e.value()) {
+                                priority_fee.set(p);
+                            }
+                        },
+                        option { initial_selected: priority_fee.read().eq(&PriorityFee::Estimate(FeeLevel::Low)), value: "{PriorityFee::Estimate(FeeLevel::Low)}", "{PriorityFee::Estimate(FeeLevel::Low)}" }
+                        option { initial_selected: priority_fee.read().eq(&PriorityFee::Estimate(FeeLevel::Medium)), value: "{PriorityFee::Estimate(FeeLevel::Medium)}", "{PriorityFee::Estimate(FeeLevel::Medium)}" }
+                        option { initial_selected: priority_fee.read().eq(&PriorityFee::Estimate(FeeLevel::High)), value: "{PriorityFee::Estimate(FeeLevel::High)}", "{PriorityFee::Estimate(FeeLevel::High)}" }
+                        option { initial_selected: matches!(*priority_fee.read(), PriorityFee::Fixed(_)), value: "{PriorityFee::Fixed(0)}", "{PriorityFee::Fixed(0)}" }
+                    }
+                }
+                if let PriorityFee::Fixed(fee) = *priority_fee.read() {
+                    div {
+                        class: "{container_class}",
+                        p {
+                            class: "{data_title_class}",
+                            "Microlamports per compute unit"
+                        }
+                        input {
+                            class: "text-right bg-transparent dark:text-white font-mono py-1 w-32 outline-none",
+                            r#type: "number",
+                            min: "0",
+                            value: "{fee}",
+                            oninput: move |e| {
+                                if let Ok(fee) = e.value().parse::<u64>() {
+                                    priority_fee.set(PriorityFee::Fixed(fee));
+                                }
+                            },
+                        }
+                    }
+                }
+                if let WalletAdapter::Connected(_) = *wallet_adapter.read() {
+                    LookupTableSettings {}
+                }
+            }
+            AddressBookSettings {}
+            NetworkSettings {}
+        }
+    }
+}
+
+pub fn LookupTableSettings() -> Element {
+    let mut lookup_table = use_lookup_table();
+    let mut is_creating = use_signal(|| false);
+
+    let container_class = "flex flex-row gap-8 justify-between w-full sm:px-1";
+    let data_title_class = "font-medium text-sm text-gray-300 my-auto";
+    let button_class =
+        "flex py-2 px-4 rounded-full text-sm font-semibold transition-colors hover-100 active-200";
+
+    rsx! {
+        div {
+            class: "{container_class}",
+            p {
+                class: "{data_title_class}",
+                "Lookup table"
+            }
+            div {
+                class: "flex flex-row gap-2",
+                if let Some(address) = lookup_table.read().0 {
+                    p {
+                        class: "font-mono text-sm my-auto truncate max-w-48",
+                        "{address}"
+                    }
+                    button {
+                        class: "{button_class}",
+                        onclick: move |_| lookup_table.set(LookupTable(None)),
+                        "Clear"
+                    }
+                } else if !*is_creating.read() {
+                    button {
+                        class: "{button_class}",
+                        onclick: move |_| is_creating.set(true),
+                        "Create"
+                    }
+                }
+            }
+        }
+        if *is_creating.read() {
+            CreateLookupTable {
+                is_creating
+            }
+        }
+    }
+}
+
+#[component]
+fn CreateLookupTable(is_creating: Signal<bool>) -> Element {
+    let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let mut lookup_table = use_lookup_table();
+    let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
+
+    let tx = use_resource(move || async move {
+        match *wallet_adapter.read() {
+            WalletAdapter::Disconnected => Err(GatewayError::WalletAdapterDisconnected),
+            WalletAdapter::Connected(signer) => {
+                let gateway = use_gateway();
+                let price = gateway
+                    .get_priority_fee(*priority_fee.read(), FeeAction::LookupTable)
+                    .await;
+                let slot = gateway.get_slot().await?;
+                let (builder, address) = tx::create_ore_lookup_table(signer, slot);
+                let tx = gateway
+                    .build_transaction(builder.priority_fee(price), None)
+                    .await?;
+                Ok((tx, address))
+            }
+        }
+    });
+
+    // Save the table once the transaction creating it lands
+    if let InvokeSignatureStatus::Done(_sig) = *invoke_signature_signal.read() {
+        if let Some(Ok((_tx, address))) = tx.cloned() {
+            lookup_table.set(LookupTable(Some(address)));
+            is_creating.set(false);
+        }
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4 w-full sm:px-1",
+            p {
+                class: "text-sm text-gray-300",
+                "A lookup table holds the ORE accounts your transactions use, so they take less space and more actions fit in one transaction. Creating one costs a small amount of SOL for rent."
+            }
+            if let Some(Ok((tx, _address))) = tx.cloned() {
+                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Create lookup table" }
+            } else if let Some(Err(err)) = tx.cloned() {
+                ErrorMessage { err }
+            } else {
+                p {
+                    class: "font-medium text-center text-sm text-gray-300",
+                    "Loading..."
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum ConnectionTest {
+    Idle,
+    Testing,
+    Passed,
+    Failed(String),
+}
+
+pub fn NetworkSettings() -> Element {
+    let mut cluster = use_cluster();
+    let mut endpoints = use_endpoints();
+    let mut rpc_url_inputs = use_signal(|| endpoints.read().rpc_urls.clone());
+    let mut api_url_input = use_signal(|| endpoints.read().api_url.clone());
+    let mut connection_test = use_signal(|| ConnectionTest::Idle);
+
+    let rpc_urls: Vec<String> = rpc_url_inputs
+        .read()
+        .iter()
+        .map(|url| url.trim().to_string())
+        .collect();
+    let api_url = api_url_input.read().trim().to_string();
+    let rpc_url_errors: Vec<bool> = rpc_urls.iter().map(|url| !is_url(url)).collect();
+    let api_url_error = !api_url.is_empty() && !is_url(&api_url);
+    let is_changed = {
+        let endpoints = endpoints.read();
+        endpoints.rpc_urls.ne(&rpc_urls) || endpoints.api_url.ne(&api_url)
+    };
+    let is_disabled = rpc_urls.is_empty() || rpc_url_errors.contains(&true) || api_url_error;
+
+    let container_class = "flex flex-col gap-2 w-full sm:px-1";
+    let section_title_class = "text-lg md:text-2xl font-bold";
+    let data_title_class = "font-medium text-sm text-gray-300";
+    let input_class = "w-full bg-transparent font-mono text-sm py-2 px-2 rounded border border-gray-300 dark:border-gray-800 focus:ring-0 outline-none";
+    let error_class =
+        "flex flex-row flex-nowrap gap-1.5 w-min text-nowrap text-red-500 font-semibold text-sm";
+    let button_class = "flex py-2 px-4 rounded-full text-sm font-semibold transition-colors hover-100 active-200 disabled:opacity-20";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+            h2 {
+                class: "{section_title_class}",
+                "Network"
+            }
+            div {
+                class: "flex flex-row gap-8 justify-between w-full sm:px-1",
+                p {
+                    class: "{data_title_class} my-auto",
+                    "Cluster"
+                }
+                select {
+                    class: "text-right bg-transparent dark:text-white hover:cursor-pointer py-1",
+                    onchange: move |e| {
+                        if let Ok(c) = Cluster::from_str(&e.value()) {
+                            let defaults = Endpoints::for_cluster(c);
+                            rpc_url_inputs.set(defaults.rpc_urls.clone());
+                            api_url_input.set(defaults.api_url.clone());
+                            connection_test.set(ConnectionTest::Idle);
+                            cluster.set(c);
+                            endpoints.set(defaults);
+                        }
+                    },
+                    option { initial_selected: cluster.read().eq(&Cluster::Mainnet), value: "{Cluster::Mainnet}", "{Cluster::Mainnet}" }
+                    option { initial_selected: cluster.read().eq(&Cluster::Devnet), value: "{Cluster::Devnet}", "{Cluster::Devnet}" }
+                    option { initial_selected: cluster.read().eq(&Cluster::Localnet), value: "{Cluster::Localnet}", "{Cluster::Localnet}" }
+                }
+            }
+            div {
+                class: "{container_class}",
+                p {
+                    class: "{data_title_class}",
+                    "RPC"
+                }
+                for (i, url) in rpc_url_inputs.read().iter().cloned().enumerate() {
+                    div {
+                        key: "{i}",
+                        class: "flex flex-col gap-2",
+                        div {
+                            class: "flex flex-row gap-2",
+                            input {
+                                class: "{input_class}",
+                                placeholder: "https://",
+                                value: "{url}",
+                                oninput: move |e| {
+                                    if let Some(url) = rpc_url_inputs.write().get_mut(i) {
+                                        *url = e.value();
+                                    }
+                                    connection_test.set(ConnectionTest::Idle);
+                                },
+                            }
+                            if rpc_url_inputs.read().len().gt(&1) {
+                                button {
+                                    class: "{button_class}",
+                                    onclick: move |_| {
+                                        rpc_url_inputs.write().remove(i);
+                                        connection_test.set(ConnectionTest::Idle);
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                        if rpc_url_errors.get(i).copied().unwrap_or(false) {
+                            p {
+                                class: "{error_class}",
+                                WarningIcon {
+                                    class: "w-4 h-4 my-auto"
+                                }
+                                "Invalid url"
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "{button_class} mr-auto -ml-4",
+                    onclick: move |_| {
+                        rpc_url_inputs.write().push("".to_string());
+                        connection_test.set(ConnectionTest::Idle);
+                    },
+                    "Add fallback"
+                }
+            }
+            div {
+                class: "{container_class}",
+                p {
+                    class: "{data_title_class}",
+                    "API"
+                }
+                input {
+                    class: "{input_class}",
+                    placeholder: "None",
+                    value: "{api_url_input}",
+                    oninput: move |e| {
+                        api_url_input.set(e.value());
+                        connection_test.set(ConnectionTest::Idle);
+                    },
+                }
+                if api_url_error {
+                    p {
+                        class: "{error_class}",
+                        WarningIcon {
+                            class: "w-4 h-4 my-auto"
+                        }
+                        "Invalid url"
+                    }
+                }
+            }
+            div {
+                class: "flex flex-row gap-2 justify-end sm:px-1",
+                match connection_test.cloned() {
+                    ConnectionTest::Idle => rsx! {},
+                    ConnectionTest::Testing => rsx! {
+                        Spinner {
+                            class: "my-auto"
+                        }
+                    },
+                    ConnectionTest::Passed => rsx! {
+                        CheckCircleIcon {
+                            class: "w-5 h-5 my-auto text-green-500"
+                        }
+                    },
+                    ConnectionTest::Failed(msg) => rsx! {
+                        p {
+                            class: "{error_class} my-auto",
+                            WarningIcon {
+                                class: "w-4 h-4 my-auto"
+                            }
+                            "{msg}"
+                        }
+                    },
+                }
+                button {
+                    class: "{button_class}",
+                    onclick: move |_| {
+                        let defaults = Endpoints::for_cluster(*cluster.read());
+                        rpc_url_inputs.set(defaults.rpc_urls);
+                        api_url_input.set(defaults.api_url);
+                        connection_test.set(ConnectionTest::Idle);
+                    },
+                    "Reset"
+                }
+                button {
+                    class: "{button_class}",
+                    disabled: is_disabled,
+                    onclick: {
+                        let rpc_urls = rpc_urls.clone();
+                        let api_url = api_url.clone();
+                        move |_| {
+                            let rpc_urls = rpc_urls.clone();
+                            let api_url = api_url.clone();
+                            connection_test.set(ConnectionTest::Testing);
+                            spawn(async move {
+                                connection_test.set(test_connection(api_url, rpc_urls).await);
+                            });
+                        }
+                    },
+                    "Test"
+                }
+                button {
+                    class: "{button_class} text-white bg-green-500 hover:bg-green-600 active:bg-green-700",
+                    disabled: is_disabled || !is_changed,
+                    onclick: move |_| {
+                        endpoints.set(Endpoints {
+                            rpc_urls: rpc_urls.clone(),
+                            api_url: api_url.clone(),
+                        });
+                    },
+                    "Save"
+                }
+            }
+            EndpointHealthTable {}
+        }
+    }
+}
+
+async fn test_connection(api_url: String, rpc_urls: Vec<String>) -> ConnectionTest {
+    for (i, rpc_url) in rpc_urls.into_iter().enumerate() {
+        let gateway = Gateway::new(api_url.clone(), vec![rpc_url]);
+        if gateway.ping_rpc().await.is_err() {
+            return ConnectionTest::Failed(format!("RPC {} unreachable", i + 1));
+        }
+    }
+    let gateway = Gateway::new(api_url.clone(), vec![]);
+    if !api_url.is_empty() && gateway.ping_api().await.is_err() {
+        return ConnectionTest::Failed("API unreachable".to_string());
+    }
+    ConnectionTest::Passed
+}
+
+pub fn EndpointHealthTable() -> Element {
+    let mut status = use_signal(|| use_gateway().rpc_status());
+    let slot = use_slot();
+
+    // Refresh the health stats collected by the gateway
+    use_future(move || async move {
+        loop {
+            async_std::task::sleep(Duration::from_secs(2)).await;
+            status.set(use_gateway().rpc_status());
+        }
+    });
+
+    let header_class = "font-medium text-xs text-gray-300 text-left py-1 pr-4";
+    let cell_class = "text-sm py-1 pr-4";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 w-full sm:px-1 overflow-x-auto",
+            div {
+                class: "flex flex-row justify-between",
+                p {
+                    class: "font-medium text-sm text-gray-300",
+                    "Health"
+                }
+                p {
+                    class: "text-sm text-gray-300",
+                    if let Some(slot) = *slot.read() {
+                        "Live · Slot {slot}"
+                    } else {
+                        "Polling"
+                    }
+                }
+            }
+            table {
+                class: "w-full",
+                thead {
+                    tr {
+                        th { class: "{header_class}", "Endpoint" }
+                        th { class: "{header_class}", "Latency" }
+                        th { class: "{header_class}", "Errors" }
+                        th { class: "{header_class}", "Status" }
+                    }
+                }
+                tbody {
+                    for endpoint in status.read().iter().cloned() {
+                        tr {
+                            td {
+                                class: "{cell_class} font-mono truncate max-w-48",
+                                "{endpoint.url}"
+                            }
+                            td {
+                                class: "{cell_class}",
+                                if let Some(latency_ms) = endpoint.latency_ms {
+                                    "{latency_ms:.0} ms"
+                                } else {
+                                    "–"
+                                }
+                            }
+                            td {
+                                class: "{cell_class}",
+                                "{endpoint.error_rate() * 100.0:.1}%"
+                            }
+                            td {
+                                class: "{cell_class}",
+                                title: endpoint.last_error.as_ref().map(|err| err.to_string()).unwrap_or_default(),
+                                if let Some(secs) = endpoint.cooldown_secs {
+                                    "Cooling down ({secs}s)"
+                                } else if endpoint.active {
+                                    "Active"
+                                } else {
+                                    "Standby"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -12,7 +12,6 @@ pub fn StopButton() -> Element {
             class: "transition transition-colors flex-shrink-0 flex w-10 h-10 justify-center rounded-full hover:bg-green-600 active:bg-green-700",
             title: "Stop mining",
             onclick: move |e| {
-                // miner.read().stop();
                 toolbar_state.pause();
                 e.stop_propagation();
             },
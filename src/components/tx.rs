@@ -1,76 +1,89 @@
-use std::str::FromStr;
-
 use dioxus::prelude::*;
-use ore_api::consts::{BUS_ADDRESSES, TREASURY_ADDRESS};
-use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use solana_client_wasm::solana_sdk::native_token::lamports_to_sol;
 use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 
 use crate::{
-    components::{BackButton, Copyable, OreIcon},
-    hooks::{use_datetime, use_explorer_transaction_url, use_transfer},
+    components::{BackButton, CodeBlock, Copyable, ErrorMessage, OreIcon},
+    hooks::{
+        address_name, use_address_book, use_datetime, use_explorer_transaction_url,
+        use_transaction_details, use_transfer,
+    },
     route::Route,
+    tx::DecodedInstruction,
 };
 
+const CONTAINER_CLASS: &str = "flex gap-8 flex-row justify-between py-2 sm:px-1";
+const TITLE_CLASS: &str = "text-gray-300 font-medium text-sm my-auto";
+const VALUE_CLASS: &str = "font-medium py-1 rounded";
+const LINK_CLASS: &str =
+    "font-medium transition-colors -ml-2 sm:ml-0 px-2 py-1 hover-100 active-200 rounded truncate";
+
 #[component]
 pub fn Tx(sig: String) -> Element {
     let nav = navigator();
-    let transfer = use_transfer(sig.clone());
+    let explorer_url = use_explorer_transaction_url(sig.clone());
+    rsx! {
+        div {
+            class: "flex flex-col gap-4 w-full -mt-3.5",
+            BackButton {
+                onclick: move |_| {
+                    nav.go_back()
+                }
+            }
+            p {
+                class: "text-3xl sm:text-4xl font-bold",
+                "Transaction"
+            }
+            div {
+                class: "flex flex-col gap-1",
+                div {
+                    class: "{CONTAINER_CLASS}",
+                    p {
+                        class: "{TITLE_CLASS}",
+                        "Signature"
+                    }
+                    Copyable {
+                        class: "truncate",
+                        value: sig.clone(),
+                        Link {
+                            class: "{LINK_CLASS} font-mono",
+                            to: explorer_url,
+                            new_tab: true,
+                            "{sig}"
+                        }
+                    }
+                }
+                TransferSummary {
+                    sig: sig.clone()
+                }
+                TransactionSummary {
+                    sig
+                }
+            }
+        }
+    }
+}
+
+// The transfer as indexed by the api, if the api is available
+#[component]
+fn TransferSummary(sig: String) -> Element {
+    let transfer = use_transfer(sig);
+    let address_book = use_address_book();
     let e = if let Some(transfer) = transfer.read().clone() {
         match transfer {
             Ok(transfer) => {
-                let transfer_memo = transfer.memo.unwrap_or("–".to_string());
-                let explorer_url = use_explorer_transaction_url(transfer.sig.clone());
                 let date = use_datetime(transfer.ts);
-                let container_class = "flex gap-8 flex-row justify-between py-2 sm:px-1";
-                let title_class = "text-gray-300 font-medium text-sm my-auto";
-                let value_class = "font-medium py-1 rounded";
-                let link_class = "font-medium transition-colors -ml-2 sm:ml-0 px-2 py-1 hover-100 active-200 rounded truncate";
-                let from_name = if let Ok(from_address) = Pubkey::from_str(&transfer.from_address) {
-                    if from_address.eq(&TREASURY_ADDRESS) {
-                        "Treasury".to_string()
-                    } else if let Some(index) = BUS_ADDRESSES
-                        .iter()
-                        .enumerate()
-                        .find(|i| (*i.1).eq(&from_address))
-                    {
-                        format!("Bus {:?}", index.0)
-                    } else {
-                        transfer.from_address.clone()
-                    }
-                } else {
-                    transfer.from_address.clone()
-                };
+                let container_class = CONTAINER_CLASS;
+                let title_class = TITLE_CLASS;
+                let value_class = VALUE_CLASS;
+                let link_class = LINK_CLASS;
+                let from_name = address_name(&address_book.read(), &transfer.from_address);
+                let to_name = address_name(&address_book.read(), &transfer.to_address);
                 rsx! {
                     div {
-                        class: "flex flex-col gap-4 w-full -mt-3.5",
-                        BackButton {
-                            onclick: move |_| {
-                                nav.go_back()
-                            }
-                        }
-                        p {
-                            class: "text-3xl sm:text-4xl font-bold",
-                            "Transaction"
-                        }
+                        class: "flex flex-col gap-4 w-full",
                         div {
                             class: "flex flex-col gap-1",
-                            div {
-                                class: "{container_class}",
-                                p {
-                                    class: "{title_class}",
-                                    "Signature"
-                                }
-                                Copyable {
-                                    class: "truncate",
-                                    value: transfer.sig.clone(),
-                                    Link {
-                                        class: "{link_class} font-mono",
-                                        to: explorer_url,
-                                        new_tab: true,
-                                        "{&transfer.sig.as_str()}"
-                                    }
-                                }
-                            }
                             div {
                                 class: "{container_class}",
                                 p {
@@ -113,7 +126,14 @@ pub fn Tx(sig: String) -> Element {
                                     Link {
                                         class: "{link_class}",
                                         to: Route::User { id: transfer.from_address.clone() },
-                                        "{from_name}"
+                                        if let Some(name) = from_name {
+                                            "{name}"
+                                        } else {
+                                            span {
+                                                class: "font-mono",
+                                                "{transfer.from_address}"
+                                            }
+                                        }
                                     }
                                 }
                             }
@@ -127,23 +147,19 @@ pub fn Tx(sig: String) -> Element {
                                     class: "truncate",
                                     value: transfer.to_address.clone(),
                                     Link {
-                                        class: "{link_class} font-mono",
+                                        class: "{link_class}",
                                         to: Route::User { id: transfer.to_address.clone() },
-                                        "{&transfer.to_address}"
+                                        if let Some(name) = to_name {
+                                            "{name}"
+                                        } else {
+                                            span {
+                                                class: "font-mono",
+                                                "{transfer.to_address}"
+                                            }
+                                        }
                                     }
                                 }
                             }
-                            div {
-                                class: "{container_class}",
-                                p {
-                                    class: "{title_class}",
-                                    "Memo"
-                                }
-                                p {
-                                    class: "{value_class}",
-                                    "{transfer_memo}"
-                                }
-                            }
                             div {
                                 class: "{container_class}",
                                 p {
@@ -171,3 +187,177 @@ pub fn Tx(sig: String) -> Element {
 
     e
 }
+
+// The transaction as confirmed onchain, with its instructions decoded
+#[component]
+fn TransactionSummary(sig: String) -> Element {
+    let tx_details = use_transaction_details(sig);
+    let e = match tx_details.cloned() {
+        Some(Ok(tx_details)) => {
+            let fee = lamports_to_sol(tx_details.fee);
+            let logs = tx_details.logs.join("\n");
+            rsx! {
+                div {
+                    class: "{CONTAINER_CLASS}",
+                    p {
+                        class: "{TITLE_CLASS}",
+                        "Status"
+                    }
+                    if let Some(err) = &tx_details.error {
+                        p {
+                            class: "{VALUE_CLASS} text-red-500 text-right break-words",
+                            "Failed: {err}"
+                        }
+                    } else {
+                        p {
+                            class: "{VALUE_CLASS} text-green-500",
+                            "Success"
+                        }
+                    }
+                }
+                div {
+                    class: "{CONTAINER_CLASS}",
+                    p {
+                        class: "{TITLE_CLASS}",
+                        "Fee"
+                    }
+                    p {
+                        class: "{VALUE_CLASS}",
+                        "{fee} SOL"
+                    }
+                }
+                if let Some(units) = tx_details.compute_units_consumed {
+                    div {
+                        class: "{CONTAINER_CLASS}",
+                        p {
+                            class: "{TITLE_CLASS}",
+                            "Compute units"
+                        }
+                        p {
+                            class: "{VALUE_CLASS}",
+                            "{units}"
+                        }
+                    }
+                }
+                if let Some(memo) = &tx_details.memo {
+                    div {
+                        class: "{CONTAINER_CLASS}",
+                        p {
+                            class: "{TITLE_CLASS}",
+                            "Memo"
+                        }
+                        p {
+                            class: "{VALUE_CLASS} text-right break-words",
+                            "{memo}"
+                        }
+                    }
+                }
+                div {
+                    class: "{CONTAINER_CLASS}",
+                    p {
+                        class: "{TITLE_CLASS}",
+                        "Slot"
+                    }
+                    p {
+                        class: "{VALUE_CLASS}",
+                        "{tx_details.slot}"
+                    }
+                }
+                div {
+                    class: "flex flex-col gap-2 py-2 sm:px-1",
+                    p {
+                        class: "{TITLE_CLASS}",
+                        "Instructions"
+                    }
+                    for (i, ix) in tx_details.instructions.into_iter().enumerate() {
+                        InstructionRow {
+                            key: "{i}",
+                            ix
+                        }
+                    }
+                }
+                if !logs.is_empty() {
+                    details {
+                        class: "py-2 sm:px-1 text-sm",
+                        summary {
+                            class: "{TITLE_CLASS} cursor-pointer",
+                            "Program logs"
+                        }
+                        div {
+                            class: "mt-2 text-xs",
+                            CodeBlock {
+                                text: logs
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Some(Err(err)) => rsx! {
+            ErrorMessage {
+                err
+            }
+        },
+        None => rsx! {
+            p {
+                class: "text-sm text-gray-300 py-2 sm:px-1",
+                "Loading transaction..."
+            }
+        },
+    };
+    e
+}
+
+#[component]
+pub fn InstructionRow(ix: DecodedInstruction) -> Element {
+    let indent = if ix.inner { "ml-4 sm:ml-6" } else { "" };
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 p-3 rounded bg-gray-100 dark:bg-gray-900 {indent}",
+            div {
+                class: "flex flex-row justify-between gap-4",
+                p {
+                    class: "font-semibold",
+                    "{ix.name}"
+                }
+                Link {
+                    class: "text-sm text-gray-300 hover:underline truncate",
+                    to: Route::User { id: ix.program_id.to_string() },
+                    "{ix.program}"
+                }
+            }
+            for (label, value) in ix.fields {
+                div {
+                    class: "flex flex-row justify-between gap-4 text-sm",
+                    p {
+                        class: "text-gray-300",
+                        "{label}"
+                    }
+                    p {
+                        class: "font-medium text-right break-all",
+                        "{value}"
+                    }
+                }
+            }
+            if !ix.accounts.is_empty() {
+                details {
+                    class: "text-sm",
+                    summary {
+                        class: "text-gray-300 cursor-pointer",
+                        "Accounts ({ix.accounts.len()})"
+                    }
+                    div {
+                        class: "flex flex-col mt-1",
+                        for account in ix.accounts {
+                            Link {
+                                class: "font-mono text-xs truncate hover:underline",
+                                to: Route::User { id: account.to_string() },
+                                "{account}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
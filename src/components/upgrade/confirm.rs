@@ -1,20 +1,17 @@
 use std::borrow::BorrowMut;
 
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::{message::Message, transaction::Transaction};
-use solana_extra_wasm::program::{
-    spl_associated_token_account::instruction::create_associated_token_account,
-    spl_token::{self, amount_to_ui_amount},
-};
-use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 
 use crate::{
-    components::{BackButton, InvokeSignature},
-    gateway::{self, ore_token_account_address, ore_token_account_address_v1},
+    components::{BackButton, ErrorMessage, InvokeSignature},
+    gateway::{FeeAction, GatewayError},
     hooks::{
-        use_gateway,
+        use_gateway, use_lookup_table, use_priority_fee,
         use_wallet_adapter::{use_wallet_adapter, InvokeSignatureStatus, WalletAdapter},
+        TransactionKind,
     },
+    tx,
 };
 
 use super::UpgradeStep;
@@ -23,46 +20,20 @@ use super::UpgradeStep;
 pub fn UpgradeConfirm(upgrade_step: Signal<UpgradeStep>, amount: u64) -> Element {
     let invoke_signature_signal = use_signal(|| InvokeSignatureStatus::Start);
     let wallet_adapter = use_wallet_adapter();
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
     let tx = use_resource(move || async move {
         match *wallet_adapter.read() {
-            WalletAdapter::Disconnected => None,
+            WalletAdapter::Disconnected => Err(GatewayError::WalletAdapterDisconnected),
             WalletAdapter::Connected(signer) => {
-                // Build ixs
-                let price = gateway::get_recent_priority_fee_estimate(true).await;
-                let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(250_000);
-                let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                let mut ixs = vec![cu_limit_ix, cu_price_ix];
-
-                // Create token account if necessary
                 let gateway = use_gateway();
-                if gateway
-                    .get_token_account_ore_from_pubkey(signer)
+                let price = gateway
+                    .get_priority_fee(*priority_fee.read(), FeeAction::Upgrade)
+                    .await;
+                let builder = tx::upgrade(signer, amount).priority_fee(price);
+                gateway
+                    .build_transaction(builder, lookup_table.read().0)
                     .await
-                    .is_err()
-                {
-                    ixs.push(create_associated_token_account(
-                        &signer,
-                        &signer,
-                        &ore_api::consts::MINT_ADDRESS,
-                        &spl_token::id(),
-                    ));
-                }
-
-                // Build upgrade ix
-                let v1_token_account_address = ore_token_account_address_v1(signer);
-                let v2_token_account_address = ore_token_account_address(signer);
-                ixs.push(ore_api::instruction::upgrade(
-                    signer,
-                    v2_token_account_address,
-                    v1_token_account_address,
-                    amount,
-                ));
-
-                // Build tx
-                let blockhash = gateway.rpc.get_latest_blockhash().await.unwrap();
-                let message = Message::new_with_blockhash(&ixs, Some(&signer), &blockhash);
-                let tx = Transaction::new_unsigned(message);
-                Some(tx)
             }
         }
     });
@@ -112,8 +83,10 @@ pub fn UpgradeConfirm(upgrade_step: Signal<UpgradeStep>, amount: u64) -> Element
                     // "{amount_to_ui_amount(amount, ore_api::consts::TOKEN_DECIMALS_V1)} ORE"
                 }
             }
-            if let Some(Some(tx)) = tx.cloned() {
-                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm" }
+            if let Some(Ok(tx)) = tx.cloned() {
+                InvokeSignature { tx: tx, signal: invoke_signature_signal, start_msg: "Confirm", kind: TransactionKind::Upgrade, amount: amount }
+            } else if let Some(Err(err)) = tx.cloned() {
+                ErrorMessage { err }
             } else {
                 p {
                     class: "font-medium text-center text-sm text-gray-300 hover:underline",
@@ -7,7 +7,10 @@ use solana_extra_wasm::program::spl_token::amount_to_ui_amount;
 
 use crate::{
     components::{ActivityTable, BackButton, Copyable, OreIcon, SendButton},
-    hooks::{use_explorer_account_url, use_ore_balance_user, use_user_proof, use_user_transfers},
+    hooks::{
+        address_name, use_address_book, use_explorer_account_url, use_ore_balance_user,
+        use_user_proof, use_user_transfers,
+    },
 };
 
 // TODO Not found
@@ -28,17 +31,8 @@ pub fn User(id: String) -> Element {
     let balance = use_ore_balance_user(user_id);
     let explorer_url = use_explorer_account_url(id.clone());
     let proof = use_user_proof(user_id);
-    let title = if let Some(index) = BUS_ADDRESSES
-        .iter()
-        .enumerate()
-        .find(|i| (*i.1).eq(&user_id))
-    {
-        format!("Bus {}", index.0)
-    } else if user_id.eq(&ore_api::consts::TREASURY_ADDRESS) {
-        "Treasury".to_string()
-    } else {
-        "User".to_string()
-    };
+    let address_book = use_address_book();
+    let title = address_name(&address_book.read(), &id).unwrap_or_else(|| "User".to_string());
 
     let description = if user_id.eq(&ore_api::consts::TREASURY_ADDRESS) {
         Some("This is a special program account responsible for minting new ORE tokens.")
@@ -1,9 +1,11 @@
 use dioxus::prelude::*;
-use solana_client_wasm::solana_sdk::transaction::Transaction;
+use solana_client_wasm::solana_sdk::transaction::VersionedTransaction;
 
 use crate::components::WarningIcon;
 use crate::components::{icons::CheckCircleIcon, Appearance, Spinner};
-use crate::hooks::{use_appearance, use_wallet_adapter, use_wallet_adapter::InvokeSignatureStatus};
+use crate::hooks::{
+    use_appearance, use_wallet_adapter, use_wallet_adapter::InvokeSignatureStatus, TransactionKind,
+};
 
 #[component]
 pub fn MountWalletAdapter() -> Element {
@@ -41,43 +43,60 @@ pub fn MountWalletAdapter() -> Element {
 
 #[component]
 pub fn InvokeSignature(
-    tx: Transaction,
+    tx: VersionedTransaction,
     signal: Signal<InvokeSignatureStatus>,
     start_msg: String,
+    kind: Option<TransactionKind>,
+    amount: Option<u64>,
 ) -> Element {
     let button_class = "w-full py-3 rounded font-semibold transition-colors text-white bg-green-500 hover:bg-green-600 active:enabled:bg-green-700";
+    let checking = use_signal(|| false);
     let error_class = "flex flex-row flex-nowrap gap-2 text-white w-min ml-auto text-nowrap bg-red-500 text-center font-semibold text-sm rounded py-1 px-2";
     rsx! {
         div {
             class: "flex flex-col gap-6",
-            if let InvokeSignatureStatus::DoneWithError = *signal.read() {
-                p {
-                    class: "{error_class}",
-                    WarningIcon {
-                        class: "w-3.5 h-3.5 my-auto"
+            if let InvokeSignatureStatus::DoneWithError(err) = &*signal.read() {
+                div {
+                    class: "flex flex-col gap-2",
+                    p {
+                        class: "{error_class}",
+                        WarningIcon {
+                            class: "w-3.5 h-3.5 my-auto"
+                        }
+                        "Transaction failed"
+                    }
+                    p {
+                        class: "text-sm text-red-500 text-right break-words",
+                        "{err}"
                     }
-                    "Transaction failed"
                 }
             }
-            if let InvokeSignatureStatus::Timeout = *signal.read() {
-                p {
-                    class: "{error_class}",
-                    WarningIcon {
-                        class: "w-3.5 h-3.5 my-auto"
+            if let InvokeSignatureStatus::Expired(_) = *signal.read() {
+                div {
+                    class: "flex flex-col gap-2",
+                    p {
+                        class: "{error_class}",
+                        WarningIcon {
+                            class: "w-3.5 h-3.5 my-auto"
+                        }
+                        "Expired"
+                    }
+                    p {
+                        class: "text-sm text-red-500 text-right",
+                        "The transaction was not confirmed before it expired. Check again in case it landed late, or retry to sign it again."
                     }
-                    "Timed out"
                 }
             }
 
             // PriorityFeeConfig { signal }
 
-            match *signal.read() {
+            match &*signal.read() {
                 InvokeSignatureStatus::Start => {
                     rsx! {
                         button {
                             class: "{button_class}",
                             onclick: move |_| {
-                                use_wallet_adapter::invoke_signature(tx.clone(), signal);
+                                use_wallet_adapter::invoke_signature(tx.clone(), signal, kind, amount);
                             },
                             "{start_msg}"
                         }
@@ -92,14 +111,42 @@ pub fn InvokeSignature(
                         }
                     }
                 }
-                InvokeSignatureStatus::DoneWithError | InvokeSignatureStatus::Timeout => {
+                InvokeSignatureStatus::Expired(sig) => {
+                    let sig = *sig;
+                    let recheck_tx = tx.clone();
+                    rsx! {
+                        div {
+                            class: "flex flex-col gap-2",
+                            button {
+                                class: "w-full py-3 rounded font-semibold transition-colors hover-100 active-200",
+                                disabled: *checking.read(),
+                                onclick: move |_| {
+                                    use_wallet_adapter::recheck_signature(recheck_tx.clone(), sig, signal, checking);
+                                },
+                                if *checking.read() {
+                                    Spinner { class: "mx-auto" }
+                                } else {
+                                    "Check again"
+                                }
+                            }
+                            button {
+                                class: "{button_class}",
+                                onclick: move |_| {
+                                    use_wallet_adapter::invoke_signature(tx.clone(), signal, kind, amount);
+                                },
+                                "Retry"
+                            }
+                        }
+                    }
+                }
+                InvokeSignatureStatus::DoneWithError(_) => {
                     // TODO: could add reset button here
                     // or other signal to user
                     rsx! {
                         button {
                             class: "{button_class}",
                             onclick: move |_| {
-                                use_wallet_adapter::invoke_signature(tx.clone(), signal);
+                                use_wallet_adapter::invoke_signature(tx.clone(), signal, kind, amount);
                             },
                             "Retry"
                         }
@@ -0,0 +1,111 @@
+use std::cell::RefCell;
+
+use futures::channel::oneshot;
+use solana_client_wasm::solana_sdk::{account::Account, pubkey::Pubkey};
+use web_time::Duration;
+
+use super::{Gateway, GatewayError, GatewayResult};
+
+/// How long the first request of a batch waits for others to join it. About one frame.
+const BATCH_WINDOW: Duration = Duration::from_millis(16);
+
+/// Max number of accounts the rpc accepts in a single getMultipleAccounts request.
+const MAX_BATCH_SIZE: usize = 100;
+
+type AccountSender = oneshot::Sender<GatewayResult<Option<Account>>>;
+
+/// Account requests waiting to be sent in the next getMultipleAccounts call.
+#[derive(Default)]
+pub struct AccountBatch {
+    queue: RefCell<Vec<(Pubkey, AccountSender)>>,
+}
+
+// Cancels the pending batch if the request which scheduled it is dropped before sending it,
+// so the other requests in the batch can fall back to fetching their accounts on their own.
+struct BatchGuard<'a> {
+    batch: &'a AccountBatch,
+    sent: bool,
+}
+
+impl Drop for BatchGuard<'_> {
+    fn drop(&mut self) {
+        if !self.sent {
+            self.batch.queue.borrow_mut().clear();
+        }
+    }
+}
+
+impl Gateway {
+    /// Fetches an account. Requests made within the same frame are coalesced into a single
+    /// getMultipleAccounts call. Returns None if the account does not exist.
+    pub async fn get_account(&self, address: Pubkey) -> GatewayResult<Option<Account>> {
+        let (sender, receiver) = oneshot::channel();
+        let first = {
+            let mut queue = self.batch.queue.borrow_mut();
+            queue.push((address, sender));
+            queue.len().eq(&1)
+        };
+
+        // The first request waits for others to join the batch, then sends it
+        if first {
+            let mut guard = BatchGuard {
+                batch: &self.batch,
+                sent: false,
+            };
+            async_std::task::sleep(BATCH_WINDOW).await;
+            let requests = self.batch.queue.take();
+            guard.sent = true;
+            self.send_batch(requests).await;
+        }
+
+        match receiver.await {
+            Ok(res) => res,
+            // The batch was cancelled, so fetch the account on its own
+            Err(_) => self.retry(|| self.try_get_account(address)).await,
+        }
+    }
+
+    async fn send_batch(&self, mut requests: Vec<(Pubkey, AccountSender)>) {
+        while !requests.is_empty() {
+            let rest = requests.split_off(requests.len().min(MAX_BATCH_SIZE));
+            let addresses: Vec<Pubkey> = requests.iter().map(|(address, _)| *address).collect();
+            log::debug!("Fetching {} accounts", addresses.len());
+            match self
+                .retry(|| self.try_get_multiple_accounts(&addresses))
+                .await
+            {
+                Ok(accounts) => {
+                    for ((_, sender), account) in requests.into_iter().zip(accounts) {
+                        sender.send(Ok(account)).ok();
+                    }
+                }
+                Err(err) => {
+                    for (_, sender) in requests {
+                        sender.send(Err(err.clone())).ok();
+                    }
+                }
+            }
+            requests = rest;
+        }
+    }
+
+    async fn try_get_multiple_accounts(
+        &self,
+        addresses: &[Pubkey],
+    ) -> GatewayResult<Vec<Option<Account>>> {
+        self.rpc()?
+            .get_multiple_accounts(addresses)
+            .await
+            .map_err(GatewayError::from)
+    }
+
+    async fn try_get_account(&self, address: Pubkey) -> GatewayResult<Option<Account>> {
+        match self.rpc()?.get_account(&address).await {
+            Ok(account) => Ok(Some(account)),
+            Err(err) => match GatewayError::from(err) {
+                GatewayError::AccountNotFound => Ok(None),
+                err => Err(err),
+            },
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+};
+
+use futures::channel::oneshot;
+use solana_client_wasm::solana_sdk::{account::Account, pubkey::Pubkey};
+use web_time::{Duration, Instant};
+
+use super::{Gateway, GatewayResult};
+
+/// The config only changes when the program admin updates it.
+pub const CONFIG_TTL: Duration = Duration::from_secs(60);
+
+/// The clock advances every slot, but mining only needs it to the nearest second.
+pub const CLOCK_TTL: Duration = Duration::from_secs(1);
+
+/// Proofs, escrows and balances are invalidated by account subscriptions and by our own
+/// transactions, so the ttl only bounds staleness when neither is available.
+pub const ACCOUNT_TTL: Duration = Duration::from_secs(5);
+
+type AccountSender = oneshot::Sender<GatewayResult<Option<Account>>>;
+
+/// Recently fetched accounts, and the requests waiting on accounts which are being fetched.
+#[derive(Default)]
+pub struct AccountCache {
+    entries: RefCell<HashMap<Pubkey, CacheEntry>>,
+    in_flight: RefCell<HashMap<Pubkey, Vec<AccountSender>>>,
+    generation: Cell<u64>,
+}
+
+struct CacheEntry {
+    account: Option<Account>,
+    fetched_at: Instant,
+}
+
+// Releases the requests waiting on a fetch if it is dropped before completing,
+// so they can fetch the account on their own.
+struct InFlightGuard<'a> {
+    cache: &'a AccountCache,
+    address: Pubkey,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.cache.in_flight.borrow_mut().remove(&self.address);
+    }
+}
+
+impl AccountCache {
+    fn get(&self, address: &Pubkey, ttl: Duration) -> Option<Option<Account>> {
+        self.entries
+            .borrow()
+            .get(address)
+            .filter(|entry| entry.fetched_at.elapsed().le(&ttl))
+            .map(|entry| entry.account.clone())
+    }
+}
+
+impl Gateway {
+    /// Fetches an account, serving it from the cache if it was fetched within the ttl.
+    /// Concurrent requests for the same account share a single fetch.
+    pub async fn get_account_cached(
+        &self,
+        address: Pubkey,
+        ttl: Duration,
+    ) -> GatewayResult<Option<Account>> {
+        if let Some(account) = self.cache.get(&address, ttl) {
+            return Ok(account);
+        }
+
+        // Wait on the fetch already in flight, if any
+        let receiver = {
+            let mut in_flight = self.cache.in_flight.borrow_mut();
+            match in_flight.get_mut(&address) {
+                Some(waiters) => {
+                    let (sender, receiver) = oneshot::channel();
+                    waiters.push(sender);
+                    Some(receiver)
+                }
+                None => {
+                    in_flight.insert(address, vec![]);
+                    None
+                }
+            }
+        };
+        if let Some(receiver) = receiver {
+            return match receiver.await {
+                Ok(res) => res,
+                Err(_) => self.get_account(address).await,
+            };
+        }
+
+        // Fetch the account and share the result with everyone waiting on it
+        let guard = InFlightGuard {
+            cache: &self.cache,
+            address,
+        };
+        let generation = self.cache.generation.get();
+        let res = self.get_account(address).await;
+        if let Ok(account) = &res {
+            // Skip caching if the account was invalidated while it was being fetched
+            if generation.eq(&self.cache.generation.get()) {
+                self.cache.entries.borrow_mut().insert(
+                    address,
+                    CacheEntry {
+                        account: account.clone(),
+                        fetched_at: Instant::now(),
+                    },
+                );
+            }
+        }
+        let waiters = self
+            .cache
+            .in_flight
+            .borrow_mut()
+            .remove(&address)
+            .unwrap_or_default();
+        drop(guard);
+        for waiter in waiters {
+            waiter.send(res.clone()).ok();
+        }
+        res
+    }
+
+    /// Drops accounts from the cache, so the next read fetches them from the rpc.
+    /// Call this once a transaction which writes to the accounts lands.
+    pub fn invalidate(&self, addresses: &[Pubkey]) {
+        let mut entries = self.cache.entries.borrow_mut();
+        for address in addresses {
+            entries.remove(address);
+        }
+        self.cache
+            .generation
+            .set(self.cache.generation.get().wrapping_add(1));
+    }
+}
@@ -0,0 +1,151 @@
+use async_std::future::timeout;
+use futures::StreamExt;
+use serde_json::json;
+use solana_client_wasm::solana_sdk::{
+    hash::Hash, signature::Signature, transaction::VersionedTransaction,
+};
+use web_time::{Duration, Instant};
+
+use super::{
+    parse_transaction_error, signature_notification_result, Gateway, GatewayError, GatewayResult,
+};
+
+// How long to wait on the signature subscription before polling the status directly
+const STATUS_INTERVAL: Duration = Duration::from_secs(1);
+
+// How often to rebroadcast a transaction while it is unconfirmed
+const REBROADCAST_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The status of a sent transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// The transaction has not been confirmed. It may still land if its blockhash is valid.
+    Pending,
+    Confirmed,
+    Failed(GatewayError),
+}
+
+impl Gateway {
+    /// Looks up the status of a transaction, including transactions which are no longer
+    /// in the rpc's recent status cache.
+    pub async fn get_signature_status(&self, sig: Signature) -> GatewayResult<SignatureStatus> {
+        self.retry(|| self.try_get_signature_status(sig)).await
+    }
+
+    pub async fn try_get_signature_status(&self, sig: Signature) -> GatewayResult<SignatureStatus> {
+        let res = self
+            .try_rpc_request(
+                "getSignatureStatuses",
+                &json!([[sig.to_string()], { "searchTransactionHistory": true }]),
+            )
+            .await?;
+        let status = &res["value"][0];
+        if status.is_null() {
+            return Ok(SignatureStatus::Pending);
+        }
+        if !status["err"].is_null() {
+            return Ok(SignatureStatus::Failed(parse_transaction_error(
+                &status["err"],
+            )));
+        }
+        match status["confirmationStatus"].as_str() {
+            Some("confirmed") | Some("finalized") => Ok(SignatureStatus::Confirmed),
+            _ => Ok(SignatureStatus::Pending),
+        }
+    }
+
+    /// Sends a signed transaction and waits for it to confirm, rebroadcasting it until it
+    /// lands or its blockhash expires. Expired transactions can never land, so they fail
+    /// with `TransactionExpired` and are safe to sign again with a new blockhash.
+    pub async fn send_and_confirm(&self, tx: &VersionedTransaction) -> GatewayResult<Signature> {
+        self.send_transaction(tx).await?;
+        self.confirm_transaction(tx).await
+    }
+
+    /// Waits for a sent transaction to confirm, rebroadcasting it until it lands or its
+    /// blockhash expires. Also resumes confirming transactions sent in an earlier session.
+    pub async fn confirm_transaction(&self, tx: &VersionedTransaction) -> GatewayResult<Signature> {
+        let sig = tx.signatures.first().copied().unwrap_or_default();
+        let blockhash = *tx.message.recent_blockhash();
+        let last_valid_block_height = self
+            .last_valid_block_heights
+            .borrow()
+            .get(&blockhash)
+            .copied();
+        let mut notifications = self.signature_subscribe(sig);
+        let mut last_broadcast = Instant::now();
+        loop {
+            // Wait for the confirmation notification
+            match timeout(STATUS_INTERVAL, notifications.next()).await {
+                Ok(Some(notification)) => {
+                    return signature_notification_result(sig, &notification)
+                        .map_err(|err| err.with_message(&tx.message));
+                }
+                // The subscription is unavailable, so fall back to polling
+                Ok(None) => async_std::task::sleep(STATUS_INTERVAL).await,
+                Err(_) => {}
+            }
+
+            // Poll in case a notification was missed
+            match self.try_get_signature_status(sig).await {
+                Ok(SignatureStatus::Confirmed) => return Ok(sig),
+                Ok(SignatureStatus::Failed(err)) => return Err(err.with_message(&tx.message)),
+                Ok(SignatureStatus::Pending) => {}
+                Err(err) => log::error!("Failed to fetch signature status: {}", err),
+            }
+
+            if !self
+                .is_blockhash_live(blockhash, last_valid_block_height)
+                .await
+            {
+                break;
+            }
+
+            // Leaders drop transactions under load, so keep sending until one lands
+            if last_broadcast.elapsed().ge(&REBROADCAST_INTERVAL) {
+                if let Err(err) = self.try_rebroadcast_transaction(tx).await {
+                    log::info!("Failed to rebroadcast {}: {}", sig, err);
+                }
+                last_broadcast = Instant::now();
+            }
+        }
+
+        // The transaction may have landed just before its blockhash expired
+        match self.get_signature_status(sig).await? {
+            SignatureStatus::Confirmed => Ok(sig),
+            SignatureStatus::Failed(err) => Err(err.with_message(&tx.message)),
+            SignatureStatus::Pending => Err(GatewayError::TransactionExpired(sig)),
+        }
+    }
+
+    // Whether transactions with the blockhash can still land. Checks the block height
+    // against the blockhash's last valid block height when it is known, and otherwise
+    // asks the rpc. Assumes the blockhash is live if the rpc can't be reached.
+    async fn is_blockhash_live(
+        &self,
+        blockhash: Hash,
+        last_valid_block_height: Option<u64>,
+    ) -> bool {
+        let res = match last_valid_block_height {
+            Some(last_valid_block_height) => self
+                .try_rpc_request("getBlockHeight", &json!([{ "commitment": "confirmed" }]))
+                .await
+                .map(|height| {
+                    height
+                        .as_u64()
+                        .map_or(true, |height| height.le(&last_valid_block_height))
+                }),
+            None => self
+                .try_rpc_request(
+                    "isBlockhashValid",
+                    &json!([blockhash.to_string(), { "commitment": "confirmed" }]),
+                )
+                .await
+                .map(|res| res["value"].as_bool().unwrap_or(true)),
+        };
+        res.unwrap_or_else(|err| {
+            log::error!("Failed to check blockhash {}: {}", blockhash, err);
+            true
+        })
+    }
+}
@@ -1,28 +1,191 @@
+use std::fmt;
+
 use async_std::future::TimeoutError;
-use solana_client_wasm::{solana_sdk::program_error::ProgramError, ClientError};
+use ore_api::error::OreError;
+use solana_client_wasm::{
+    solana_sdk::{
+        instruction::InstructionError,
+        message::{CompileError, VersionedMessage},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::TransactionError,
+    },
+    ClientError,
+};
 
 pub type GatewayResult<T> = Result<T, GatewayError>;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum GatewayError {
-    FailedDeserialization,
+    FailedDeserialization(Pubkey),
+    InvalidAccountOwner {
+        address: Pubkey,
+        owner: Pubkey,
+    },
     FailedAta,
-    TransactionTimeout,
+    TransactionExpired(Signature),
     NetworkUnavailable,
+    RateLimited,
     AccountNotFound,
     RetryFailed,
     TimeoutError,
     SignatureDenied,
-    // SimulationFailed,
-    RequestFailed,
-    ProgramBuilderFailed,
+    SimulationFailed {
+        error: Box<GatewayError>,
+        logs: Vec<String>,
+    },
+    ApiUnavailable,
+    RpcUnavailable,
+    RequestFailed {
+        status: Option<u16>,
+        message: String,
+    },
+    RpcError {
+        code: Option<i64>,
+        message: String,
+    },
+    InstructionError {
+        index: u8,
+        code: u32,
+        program_id: Option<Pubkey>,
+    },
+    ProgramBuilderFailed(String),
     WalletAdapterDisconnected,
-    Unknown,
+    Unknown(String),
+}
+
+impl GatewayError {
+    /// Attaches the program id of the failed instruction, so custom errors can be
+    /// decoded against the program that returned them.
+    pub fn with_message(self, message: &VersionedMessage) -> Self {
+        match self {
+            GatewayError::SimulationFailed { error, logs } => GatewayError::SimulationFailed {
+                error: Box::new(error.with_message(message)),
+                logs,
+            },
+            GatewayError::InstructionError {
+                index,
+                code,
+                program_id: None,
+            } => GatewayError::InstructionError {
+                index,
+                code,
+                program_id: message
+                    .instructions()
+                    .get(index as usize)
+                    .and_then(|ix| {
+                        // Programs are never loaded from lookup tables
+                        message
+                            .static_account_keys()
+                            .get(ix.program_id_index as usize)
+                    })
+                    .copied(),
+            },
+            err => err,
+        }
+    }
+
+    /// Whether the error comes from the endpoint or the network rather than the request, so
+    /// sending the request again may succeed.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            GatewayError::RateLimited
+            | GatewayError::NetworkUnavailable
+            | GatewayError::TimeoutError => true,
+            GatewayError::RequestFailed { status, .. } => {
+                status.map_or(true, |status| status.ge(&500))
+            }
+            _ => false,
+        }
+    }
+
+    /// Decodes a custom instruction error returned by the ORE program.
+    pub fn ore_error(&self) -> Option<OreError> {
+        match self {
+            GatewayError::InstructionError {
+                code,
+                program_id: Some(program_id),
+                ..
+            } if program_id.eq(&ore_api::ID) => decode_ore_error(*code),
+            GatewayError::SimulationFailed { error, .. } => error.ore_error(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GatewayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GatewayError::FailedDeserialization(address) => {
+                write!(f, "Failed to read account data of {}", address)
+            }
+            GatewayError::InvalidAccountOwner { address, owner } => {
+                write!(
+                    f,
+                    "Account {} is owned by unexpected program {}",
+                    address, owner
+                )
+            }
+            GatewayError::FailedAta => write!(f, "Token account does not exist"),
+            GatewayError::TransactionExpired(sig) => {
+                write!(f, "Transaction {} expired before it was confirmed", sig)
+            }
+            GatewayError::NetworkUnavailable => write!(f, "Network unavailable"),
+            GatewayError::RateLimited => write!(f, "RPC rate limited"),
+            GatewayError::AccountNotFound => write!(f, "Account not found"),
+            GatewayError::RetryFailed => write!(f, "Request failed after several attempts"),
+            GatewayError::TimeoutError => write!(f, "Request timed out"),
+            GatewayError::SignatureDenied => write!(f, "Signature denied"),
+            GatewayError::SimulationFailed { error, .. } => {
+                write!(f, "Simulation failed: {}", error)
+            }
+            GatewayError::ApiUnavailable => write!(f, "No API configured for this network"),
+            GatewayError::RpcUnavailable => write!(f, "No RPC configured for this network"),
+            GatewayError::RequestFailed { status, message } => match status {
+                Some(status) => write!(f, "Request failed with status {}: {}", status, message),
+                None => write!(f, "Request failed: {}", message),
+            },
+            GatewayError::RpcError { code, message } => match code {
+                Some(code) => write!(f, "RPC error {}: {}", code, message),
+                None => write!(f, "RPC error: {}", message),
+            },
+            GatewayError::InstructionError {
+                index,
+                code,
+                program_id,
+            } => match self.ore_error() {
+                Some(err) => write!(f, "{}", err),
+                None => match program_id {
+                    Some(program_id) => write!(
+                        f,
+                        "Instruction {} failed with error 0x{:x} in program {}",
+                        index, code, program_id
+                    ),
+                    None => write!(f, "Instruction {} failed with error 0x{:x}", index, code),
+                },
+            },
+            GatewayError::ProgramBuilderFailed(message) => {
+                write!(f, "Failed to build instruction: {}", message)
+            }
+            GatewayError::WalletAdapterDisconnected => write!(f, "Wallet disconnected"),
+            GatewayError::Unknown(message) => write!(f, "{}", message),
+        }
+    }
 }
 
 impl From<reqwest::Error> for GatewayError {
-    fn from(_value: reqwest::Error) -> Self {
-        GatewayError::RequestFailed
+    fn from(value: reqwest::Error) -> Self {
+        if value
+            .status()
+            .is_some_and(|status| status.as_u16().eq(&429))
+        {
+            return GatewayError::RateLimited;
+        }
+        GatewayError::RequestFailed {
+            status: value.status().map(|status| status.as_u16()),
+            message: value.to_string(),
+        }
     }
 }
 
@@ -34,24 +197,197 @@ impl From<TimeoutError> for GatewayError {
 
 impl From<ClientError> for GatewayError {
     fn from(value: ClientError) -> Self {
-        let msg = value.to_string();
-        if msg.starts_with("Client error: Invalid param: could not find account")
-            || msg.starts_with("Client error: AccountNotFound: ")
-            || msg.ends_with("not found.")
-        {
-            GatewayError::AccountNotFound
-        } else if msg.starts_with("Client error: error sending request") {
-            GatewayError::NetworkUnavailable
-        } else {
-            log::info!("Err: {:?}", msg);
-            GatewayError::Unknown
+        parse_client_error(value.to_string())
+    }
+}
+
+// Client errors only carry a message, so they are told apart by its format
+fn parse_client_error(msg: String) -> GatewayError {
+    if msg.starts_with("Client error: Invalid param: could not find account")
+        || msg.starts_with("Client error: AccountNotFound: ")
+        || msg.ends_with("not found.")
+    {
+        GatewayError::AccountNotFound
+    } else if msg.starts_with("Client error: error sending request") {
+        GatewayError::NetworkUnavailable
+    } else if let Some((index, code)) = parse_instruction_error(&msg) {
+        GatewayError::InstructionError {
+            index,
+            code,
+            program_id: None,
+        }
+    } else if is_rate_limited(&msg) {
+        GatewayError::RateLimited
+    } else if let Some(code) = parse_rpc_error_code(&msg) {
+        GatewayError::RpcError {
+            code: Some(code),
+            message: msg,
+        }
+    } else {
+        log::info!("Err: {:?}", msg);
+        GatewayError::Unknown(msg)
+    }
+}
+
+impl From<TransactionError> for GatewayError {
+    fn from(value: TransactionError) -> Self {
+        match value {
+            TransactionError::InstructionError(index, InstructionError::Custom(code)) => {
+                GatewayError::InstructionError {
+                    index,
+                    code,
+                    program_id: None,
+                }
+            }
+            err => GatewayError::Unknown(err.to_string()),
         }
     }
 }
 
+impl From<CompileError> for GatewayError {
+    fn from(value: CompileError) -> Self {
+        GatewayError::Unknown(format!("Failed to compile transaction: {}", value))
+    }
+}
+
 impl From<ProgramError> for GatewayError {
     fn from(value: ProgramError) -> Self {
         log::error!("err: {}", value);
-        GatewayError::ProgramBuilderFailed
+        GatewayError::ProgramBuilderFailed(value.to_string())
+    }
+}
+
+/// The json rpc error code rpcs return when rate limiting requests.
+pub const RATE_LIMITED_CODE: i64 = -32429;
+
+const ORE_ERRORS: [OreError; 8] = [
+    OreError::NeedsReset,
+    OreError::HashInvalid,
+    OreError::HashTooEasy,
+    OreError::ClaimTooLarge,
+    OreError::ClockInvalid,
+    OreError::Spam,
+    OreError::MaxSupply,
+    OreError::AuthFailed,
+];
+
+fn decode_ore_error(code: u32) -> Option<OreError> {
+    ORE_ERRORS.into_iter().find(|err| (*err as u32).eq(&code))
+}
+
+// Rpcs rate limit with an http 429, or with a json rpc error
+fn is_rate_limited(msg: &str) -> bool {
+    msg.contains("HTTP status client error (429 ")
+        || parse_rpc_error_code(msg).eq(&Some(RATE_LIMITED_CODE))
+}
+
+// Parses errors of the form "RPC response error -32002: ..."
+fn parse_rpc_error_code(msg: &str) -> Option<i64> {
+    let (_, rest) = msg.split_once("RPC response error ")?;
+    let (code, _) = rest.split_once(':')?;
+    code.trim().parse().ok()
+}
+
+// Parses custom instruction errors, formatted either as
+// "Error processing Instruction 2: custom program error: 0x1"
+// or as "InstructionError(2, Custom(1))"
+fn parse_instruction_error(msg: &str) -> Option<(u8, u32)> {
+    if let Some((_, rest)) = msg.split_once("Error processing Instruction ") {
+        let (index, rest) = rest.split_once(':')?;
+        let (_, code) = rest.split_once("custom program error: 0x")?;
+        let code: String = code.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+        return Some((
+            index.trim().parse().ok()?,
+            u32::from_str_radix(&code, 16).ok()?,
+        ));
+    }
+    if let Some((_, rest)) = msg.split_once("InstructionError(") {
+        let (index, rest) = rest.split_once(',')?;
+        let (_, code) = rest.split_once("Custom(")?;
+        let (code, _) = code.split_once(')')?;
+        return Some((index.trim().parse().ok()?, code.trim().parse().ok()?));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(msg: &str) -> GatewayError {
+        parse_client_error(msg.to_string())
+    }
+
+    #[test]
+    fn only_transient_errors_are_retried() {
+        for err in [
+            GatewayError::RateLimited,
+            GatewayError::NetworkUnavailable,
+            GatewayError::TimeoutError,
+            GatewayError::RequestFailed {
+                status: Some(503),
+                message: "Service Unavailable".to_string(),
+            },
+            GatewayError::RequestFailed {
+                status: None,
+                message: "connection reset".to_string(),
+            },
+        ] {
+            assert!(err.is_transient(), "{:?}", err);
+        }
+        for err in [
+            GatewayError::AccountNotFound,
+            GatewayError::RequestFailed {
+                status: Some(404),
+                message: "Not Found".to_string(),
+            },
+            GatewayError::RpcError {
+                code: Some(-32002),
+                message: "Transaction simulation failed".to_string(),
+            },
+            GatewayError::InstructionError {
+                index: 0,
+                code: 1,
+                program_id: None,
+            },
+            GatewayError::Unknown("invalid transaction".to_string()),
+        ] {
+            assert!(!err.is_transient(), "{:?}", err);
+        }
+    }
+
+    #[test]
+    fn rate_limits_on_http_429_and_rpc_code() {
+        assert_eq!(
+            parse("Client error: HTTP status client error (429 Too Many Requests) for url (https://rpc.example.com/)"),
+            GatewayError::RateLimited
+        );
+        assert_eq!(
+            parse("Client error: RPC response error -32429: rate limit exceeded"),
+            GatewayError::RateLimited
+        );
+    }
+
+    #[test]
+    fn instruction_errors_containing_429_are_not_rate_limited() {
+        assert_eq!(
+            parse("Client error: RPC response error -32002: Transaction simulation failed: Error processing Instruction 1: custom program error: 0x429"),
+            GatewayError::InstructionError {
+                index: 1,
+                code: 0x429,
+                program_id: None,
+            }
+        );
+        assert_eq!(
+            parse("Client error: RPC response error -32002: Transaction simulation failed: Program 4293mEndX consumed 42900 of 200000 compute units; Too Many Requests"),
+            GatewayError::RpcError {
+                code: Some(-32002),
+                message: "Client error: RPC response error -32002: Transaction simulation failed: Program 4293mEndX consumed 42900 of 200000 compute units; Too Many Requests".to_string(),
+            }
+        );
+        assert!(matches!(
+            parse("Client error: invalid signature 3x429kQ"),
+            GatewayError::Unknown(_)
+        ));
     }
 }
@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use ore_api::{consts::MINT_ADDRESS, instruction::OreInstruction};
+use ore_types::{response::ListTransfersResponse, Transfer, TransferType};
+use serde_json::{json, Value};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+use super::{ore_token_account_address, proof_pubkey, Gateway, GatewayError, GatewayResult};
+
+// Max number of signatures the rpc returns per request
+const MAX_SIGNATURES: usize = 1000;
+
+/// What a transaction in the activity feed did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoryKind {
+    Claim,
+    Mine,
+    Stake,
+    Transfer,
+    Upgrade,
+}
+
+/// A row of the activity feed. Built from the api's transfers when it is available, and
+/// otherwise parsed from the user's transactions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub sig: String,
+    pub kind: HistoryKind,
+    pub from_address: String,
+    pub to_address: String,
+    pub amount: u64,
+    pub ts: i64,
+    pub memo: Option<String>,
+}
+
+/// Where a page of activity was read from. The api counts transfers and the rpc counts
+/// signatures, so an offset only means something to the source that served it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistorySource {
+    Api,
+    Rpc,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryPage {
+    pub data: Vec<HistoryEntry>,
+    pub has_more: bool,
+    pub source: HistorySource,
+}
+
+impl From<Transfer> for HistoryEntry {
+    fn from(transfer: Transfer) -> Self {
+        HistoryEntry {
+            sig: transfer.sig,
+            kind: match transfer.transfer_type {
+                TransferType::Claim => HistoryKind::Claim,
+                TransferType::Mine => HistoryKind::Mine,
+                TransferType::Spl => HistoryKind::Transfer,
+                TransferType::Upgrade => HistoryKind::Upgrade,
+            },
+            from_address: transfer.from_address,
+            to_address: transfer.to_address,
+            amount: transfer.amount as u64,
+            ts: transfer.ts,
+            memo: transfer.memo,
+        }
+    }
+}
+
+impl From<ListTransfersResponse> for HistoryPage {
+    fn from(res: ListTransfersResponse) -> Self {
+        HistoryPage {
+            data: res.data.into_iter().map(HistoryEntry::from).collect(),
+            has_more: res.has_more,
+            source: HistorySource::Api,
+        }
+    }
+}
+
+impl Gateway {
+    /// Lists a user's activity from the api, falling back to the user's transaction history
+    /// on the rpc if the api is unavailable. Later pages should pass the source of the first,
+    /// so the offset keeps paging the same list.
+    pub async fn list_history(
+        &self,
+        user: Pubkey,
+        offset: u64,
+        limit: usize,
+        source: Option<HistorySource>,
+    ) -> GatewayResult<HistoryPage> {
+        match source {
+            Some(HistorySource::Api) => self
+                .list_transfers(Some(user), offset, limit)
+                .await
+                .map(HistoryPage::from),
+            Some(HistorySource::Rpc) => self.get_local_history(user, offset, limit).await,
+            None => match self.list_transfers(Some(user), offset, limit).await {
+                Ok(res) => Ok(res.into()),
+                Err(err) => {
+                    log::info!("Falling back to local history: {}", err);
+                    self.get_local_history(user, offset, limit).await
+                }
+            },
+        }
+    }
+
+    /// Builds a user's activity from the signatures of their ORE token account and proof.
+    /// Transactions which didn't move ORE are left out, so pages may be short.
+    pub async fn get_local_history(
+        &self,
+        user: Pubkey,
+        offset: u64,
+        limit: usize,
+    ) -> GatewayResult<HistoryPage> {
+        let offset = offset as usize;
+        let fetch_limit = (offset + limit + 1).min(MAX_SIGNATURES);
+        let (token_sigs, proof_sigs) = futures::join!(
+            self.get_signatures_for_address(ore_token_account_address(user), fetch_limit),
+            self.get_signatures_for_address(proof_pubkey(user), fetch_limit),
+        );
+
+        // Merge the signatures of both accounts, newest first
+        let mut sigs: Vec<(u64, String)> = token_sigs?.into_iter().chain(proof_sigs?).collect();
+        sigs.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+        sigs.dedup_by(|a, b| a.1.eq(&b.1));
+        let has_more = sigs.len().gt(&(offset + limit));
+
+        let page = sigs.into_iter().skip(offset).take(limit);
+        let txs = futures::future::join_all(page.map(|(_, sig)| async move {
+            let tx = self.get_parsed_transaction(&sig).await;
+            (sig, tx)
+        }))
+        .await;
+        let mut data = vec![];
+        for (sig, tx) in txs {
+            // One missing transaction shouldn't hide the rest of the page
+            let tx = match tx {
+                Ok(tx) => tx,
+                Err(err) => {
+                    log::error!("Failed to fetch transaction {}: {}", sig, err);
+                    continue;
+                }
+            };
+            if let Some(entry) = parse_history_entry(user, sig, &tx) {
+                data.push(entry);
+            }
+        }
+        Ok(HistoryPage {
+            data,
+            has_more,
+            source: HistorySource::Rpc,
+        })
+    }
+
+    // Returns the slot and signature of an address's successful transactions, newest first
+    pub(super) async fn get_signatures_for_address(
+        &self,
+        address: Pubkey,
+        limit: usize,
+    ) -> GatewayResult<Vec<(u64, String)>> {
+        let res = self
+            .rpc_request(
+                "getSignaturesForAddress",
+                json!([address.to_string(), { "limit": limit, "commitment": "confirmed" }]),
+            )
+            .await?;
+        let sigs = res
+            .as_array()
+            .ok_or(GatewayError::Unknown("Invalid signatures".to_string()))?;
+        Ok(sigs
+            .iter()
+            .filter(|sig| sig["err"].is_null())
+            .filter_map(|sig| {
+                Some((
+                    sig["slot"].as_u64()?,
+                    sig["signature"].as_str()?.to_string(),
+                ))
+            })
+            .collect())
+    }
+
+    pub(super) async fn get_parsed_transaction(&self, sig: &str) -> GatewayResult<Value> {
+        self.rpc_request(
+            "getTransaction",
+            json!([sig, {
+                "encoding": "jsonParsed",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0,
+            }]),
+        )
+        .await
+    }
+}
+
+// Parses a jsonParsed transaction into an activity row. Transactions which called the ORE
+// program are described by its instruction, and others by how they moved the user's ORE.
+fn parse_history_entry(user: Pubkey, sig: String, tx: &Value) -> Option<HistoryEntry> {
+    let ixs = parsed_instructions(tx);
+    let memo = ixs
+        .iter()
+        .find(|ix| ix["program"].as_str().eq(&Some("spl-memo")))
+        .and_then(|ix| ix["parsed"].as_str())
+        .map(String::from);
+    let user = user.to_string();
+    let mut entry = HistoryEntry {
+        sig,
+        kind: HistoryKind::Transfer,
+        from_address: user.clone(),
+        to_address: user.clone(),
+        amount: 0,
+        ts: tx["blockTime"].as_i64().unwrap_or_default(),
+        memo,
+    };
+
+    // Find the first ORE instruction which moves ORE
+    let ore_program_id = ore_api::ID.to_string();
+    for ix in ixs
+        .iter()
+        .filter(|ix| ix["programId"].as_str().eq(&Some(ore_program_id.as_str())))
+    {
+        let Some(data) = ix["data"]
+            .as_str()
+            .and_then(|data| bs58::decode(data).into_vec().ok())
+        else {
+            continue;
+        };
+        let Some(discriminator) = data.first().copied() else {
+            continue;
+        };
+        let kind = if discriminator.eq(&(OreInstruction::Mine as u8)) {
+            entry.amount = mine_reward(tx).unwrap_or_default();
+            HistoryKind::Mine
+        } else if discriminator.eq(&(OreInstruction::Claim as u8)) {
+            entry.amount = instruction_amount(&data)?;
+            HistoryKind::Claim
+        } else if discriminator.eq(&(OreInstruction::Stake as u8)) {
+            entry.amount = instruction_amount(&data)?;
+            HistoryKind::Stake
+        } else if discriminator.eq(&(OreInstruction::Upgrade as u8)) {
+            entry.amount = instruction_amount(&data)?;
+            HistoryKind::Upgrade
+        } else {
+            continue;
+        };
+        entry.kind = kind;
+        return Some(entry);
+    }
+
+    // Otherwise describe how the transaction moved the user's ORE
+    let deltas = ore_balance_deltas(tx);
+    let delta = deltas.get(&user).copied().unwrap_or_default();
+    if delta.eq(&0) {
+        return None;
+    }
+    let counterparty = deltas
+        .iter()
+        .find(|(owner, d)| owner.ne(&&user) && d.signum().eq(&-delta.signum()))
+        .map(|(owner, _)| owner.clone())?;
+    if delta.lt(&0) {
+        entry.to_address = counterparty;
+    } else {
+        entry.from_address = counterparty;
+    }
+    entry.amount = delta.unsigned_abs() as u64;
+    Some(entry)
+}
+
+// The top level and inner instructions of a transaction, in execution order
+pub(super) fn parsed_instructions(tx: &Value) -> Vec<Value> {
+    let mut ixs = vec![];
+    let inner = tx["meta"]["innerInstructions"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    for (i, ix) in tx["transaction"]["message"]["instructions"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+    {
+        ixs.push(ix);
+        if let Some(inner) = inner
+            .iter()
+            .find(|inner| inner["index"].as_u64().eq(&Some(i as u64)))
+        {
+            ixs.extend(
+                inner["instructions"]
+                    .as_array()
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+    }
+    ixs
+}
+
+// Reads the amount argument which follows the discriminator of claim, stake and upgrade
+fn instruction_amount(data: &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(1..9)?.try_into().ok()?))
+}
+
+// Reads the reward of a mine instruction from the event it returns, which holds the
+// difficulty, reward and timing of the solution
+fn mine_reward(tx: &Value) -> Option<u64> {
+    let return_data = &tx["meta"]["returnData"];
+    if return_data["programId"]
+        .as_str()
+        .ne(&Some(ore_api::ID.to_string().as_str()))
+    {
+        return None;
+    }
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(return_data["data"][0].as_str()?)
+        .ok()?;
+    Some(u64::from_le_bytes(data.get(8..16)?.try_into().ok()?))
+}
+
+// The change in ORE balance of each token account owner
+fn ore_balance_deltas(tx: &Value) -> HashMap<String, i128> {
+    let mint = MINT_ADDRESS.to_string();
+    let mut deltas = HashMap::new();
+    for (key, sign) in [("preTokenBalances", -1), ("postTokenBalances", 1)] {
+        for balance in tx["meta"][key].as_array().cloned().unwrap_or_default() {
+            if balance["mint"].as_str().ne(&Some(mint.as_str())) {
+                continue;
+            }
+            let (Some(owner), Some(amount)) = (
+                balance["owner"].as_str(),
+                balance["uiTokenAmount"]["amount"]
+                    .as_str()
+                    .and_then(|amount| amount.parse::<i128>().ok()),
+            ) else {
+                continue;
+            };
+            *deltas.entry(owner.to_string()).or_insert(0) += sign * amount;
+        }
+    }
+    deltas
+}
@@ -1,10 +1,26 @@
+mod batch;
+mod cache;
+mod confirm;
 mod error;
+mod history;
+mod pay;
 mod pfee;
+mod pool;
 mod pubkey;
+mod pubsub;
+mod simulate;
+mod transaction;
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
 
 use async_std::future::{timeout, Future};
+use base64::Engine;
 use cached::proc_macro::cached;
 pub use error::*;
+use futures::StreamExt;
 use ore_api::{
     consts::CONFIG_ADDRESS,
     state::{Config, Proof},
@@ -12,73 +28,269 @@ use ore_api::{
 use ore_relayer_api::state::Escrow;
 use ore_types::{response::ListTransfersResponse, Transfer};
 use ore_utils::AccountDeserialize;
+use serde_json::{json, Value};
 use solana_client_wasm::{
-    solana_sdk::{clock::Clock, hash::Hash, pubkey::Pubkey, signature::Signature, sysvar},
+    solana_sdk::{
+        clock::Clock, hash::Hash, program_pack::Pack, pubkey::Pubkey, signature::Signature, sysvar,
+        transaction::TransactionError,
+    },
     WasmClient,
 };
 use solana_extra_wasm::{
-    account_decoder::parse_token::UiTokenAccount,
-    program::spl_associated_token_account::get_associated_token_address,
-    transaction_status::TransactionConfirmationStatus,
+    account_decoder::parse_token::{token_amount_to_ui_amount, UiTokenAccount, UiTokenAmount},
+    program::{spl_associated_token_account::get_associated_token_address, spl_token},
 };
-use web_time::Duration;
+use web_time::{Duration, Instant};
 
+pub use batch::*;
+pub use cache::*;
+pub use confirm::*;
+pub use history::*;
+pub use pay::*;
 pub use pfee::*;
+pub use pool::*;
 pub use pubkey::*;
+pub use pubsub::*;
+pub use simulate::*;
+pub use transaction::*;
 
-pub const API_URL: &str = "https://ore-api-lthm.onrender.com";
+pub const DEFAULT_API_URL: &str = "https://ore-api-lthm.onrender.com";
 
-// pub const RPC_URL: &str = "https://emelia-3g4m0w-fast-devnet.helius-rpc.com"; // Devnet
+pub const DEFAULT_RPC_URL: &str =
+    "https://rpc.ironforge.network/mainnet?apiKey=01J4NJDYJXSGJYE3AN6VXEB5VR";
 
-// pub const RPC_URL: &str = "https://amaleta-5y8tse-fast-mainnet.helius-rpc.com"; // Mainnet
+pub const DEVNET_RPC_URL: &str = "https://api.devnet.solana.com";
 
-pub const RPC_URL: &str = "https://rpc.ironforge.network/mainnet?apiKey=01J4NJDYJXSGJYE3AN6VXEB5VR";
+pub const LOCALNET_RPC_URL: &str = "http://localhost:8899";
 
 // const GATEWAY_RETRIES: usize = 128;
-const CONFIRM_RETRIES: usize = 20;
-const CONFIRM_DELAY: u64 = 500;
+
+// How long to wait on an account subscription before polling the account directly
+const SUBSCRIPTION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+// How often to poll an account when subscriptions are unavailable
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// Max number of blockhashes to remember the validity of
+const MAX_BLOCKHASHES: usize = 64;
 
 pub struct Gateway {
-    pub rpc: WasmClient,
+    rpcs: RpcPool,
+    pubsub: PubSub,
+    batch: AccountBatch,
+    cache: AccountCache,
+    unsupported_fee_estimators: RefCell<HashSet<(String, &'static str)>>,
+    last_valid_block_heights: RefCell<HashMap<Hash, u64>>,
     api_url: String,
 }
 
 impl Gateway {
-    pub fn new(api_url: String, rpc_url: String) -> Self {
+    pub fn new(api_url: String, rpc_urls: Vec<String>) -> Self {
+        let pubsub_urls = rpc_urls.iter().map(|url| pubsub_url(url)).collect();
         Gateway {
+            rpcs: RpcPool::new(rpc_urls),
+            pubsub: PubSub::new(pubsub_urls),
+            batch: AccountBatch::default(),
+            cache: AccountCache::default(),
+            unsupported_fee_estimators: RefCell::new(HashSet::new()),
+            last_valid_block_heights: RefCell::new(HashMap::new()),
             api_url,
-            rpc: WasmClient::new(&rpc_url),
         }
     }
 
-    pub async fn get_clock(&self) -> GatewayResult<Clock> {
-        retry(|| self.try_get_clock()).await
+    /// The rpc client of the healthiest endpoint in the pool.
+    pub fn rpc(&self) -> GatewayResult<&WasmClient> {
+        self.rpcs
+            .current()
+            .map(|endpoint| &endpoint.client)
+            .ok_or(GatewayError::RpcUnavailable)
     }
 
-    pub async fn try_get_clock(&self) -> GatewayResult<Clock> {
-        let data = self
-            .rpc
-            .get_account_data(&sysvar::clock::ID)
+    pub fn rpc_url(&self) -> GatewayResult<&str> {
+        self.rpcs
+            .current()
+            .map(|endpoint| endpoint.url.as_str())
+            .ok_or(GatewayError::RpcUnavailable)
+    }
+
+    pub fn rpc_urls(&self) -> Vec<String> {
+        self.rpcs.urls()
+    }
+
+    pub fn rpc_status(&self) -> Vec<EndpointStatus> {
+        self.rpcs.status()
+    }
+
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    /// Notifies with the account's data, base64 encoded, whenever the account changes.
+    pub fn account_subscribe(&self, address: Pubkey) -> PubSubStream {
+        self.pubsub.subscribe(
+            "accountSubscribe",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )
+    }
+
+    /// Notifies once, when the transaction is confirmed.
+    pub fn signature_subscribe(&self, sig: Signature) -> PubSubStream {
+        self.pubsub.subscribe(
+            "signatureSubscribe",
+            json!([sig.to_string(), { "commitment": "confirmed" }]),
+        )
+    }
+
+    /// Notifies whenever the validator processes a new slot.
+    pub fn slot_subscribe(&self) -> PubSubStream {
+        self.pubsub.subscribe("slotSubscribe", json!([]))
+    }
+
+    // Not every cluster has an api, in which case api requests fail immediately
+    fn try_api_url(&self) -> GatewayResult<&str> {
+        if self.api_url.is_empty() {
+            Err(GatewayError::ApiUnavailable)
+        } else {
+            Ok(&self.api_url)
+        }
+    }
+
+    /// Retries a request with exponential backoff, as long as it fails with a transient error.
+    /// Failures are recorded against the endpoint that served the request, and if the
+    /// endpoint is unavailable or rate limiting us, the next attempt fails over to the next
+    /// endpoint in the pool.
+    pub async fn retry<F, Fut, T>(&self, f: F) -> GatewayResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = GatewayResult<T>>,
+    {
+        const MAX_RETRIES: u32 = 8;
+        const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+        const TIMEOUT: Duration = Duration::from_secs(8);
+        let mut backoff = INITIAL_BACKOFF;
+        for attempt in 0..MAX_RETRIES {
+            let index = self
+                .rpcs
+                .current_index()
+                .ok_or(GatewayError::RpcUnavailable)?;
+            let timer = Instant::now();
+            let err = match timeout(TIMEOUT, f()).await {
+                Ok(Ok(result)) => {
+                    self.rpcs.record_success(index, timer.elapsed());
+                    return Ok(result);
+                }
+                // The endpoint served the request, but retrying won't change the result
+                Ok(Err(err)) if !err.is_transient() => {
+                    self.rpcs.record_success(index, timer.elapsed());
+                    return Err(err);
+                }
+                Ok(Err(e)) => e,
+                Err(_) => GatewayError::TimeoutError,
+            };
+            let failed_over = self
+                .rpcs
+                .record_failure(index, err.clone(), timer.elapsed());
+            if attempt.ge(&(MAX_RETRIES - 1)) {
+                return match err {
+                    GatewayError::TimeoutError => Err(GatewayError::RetryFailed),
+                    _ => Err(err),
+                };
+            }
+            if !failed_over {
+                async_std::task::sleep(backoff).await;
+                backoff *= 2; // Exponential backoff
+            }
+        }
+
+        Err(GatewayError::RetryFailed)
+    }
+
+    /// Sends a raw json rpc request to the current rpc endpoint, for methods the rpc
+    /// client does not support. Returns the result of the response.
+    pub async fn rpc_request(&self, method: &str, params: Value) -> GatewayResult<Value> {
+        self.retry(|| self.try_rpc_request(method, &params)).await
+    }
+
+    async fn try_rpc_request(&self, method: &str, params: &Value) -> GatewayResult<Value> {
+        let req = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let mut res = reqwest::Client::new()
+            .post(self.rpc_url()?)
+            .json(&req)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+        // Transactions which fail preflight come back with the simulation result
+        let preflight = &res["error"]["data"];
+        if !preflight["err"].is_null() {
+            return Err(GatewayError::SimulationFailed {
+                error: Box::new(parse_transaction_error(&preflight["err"])),
+                logs: parse_logs(&preflight["logs"]),
+            });
+        }
+        if !res["error"].is_null() {
+            let code = res["error"]["code"].as_i64();
+            if code.eq(&Some(RATE_LIMITED_CODE)) {
+                return Err(GatewayError::RateLimited);
+            }
+            return Err(GatewayError::RpcError {
+                code,
+                message: res["error"]["message"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+        Ok(res["result"].take())
+    }
+
+    // Checks that the rpc endpoint is reachable and serving requests
+    pub async fn ping_rpc(&self) -> GatewayResult<()> {
+        self.rpc()?
+            .get_latest_blockhash()
             .await
-            .map_err(GatewayError::from)?;
-        bincode::deserialize::<Clock>(&data).or(Err(GatewayError::FailedDeserialization))
+            .map(|_| ())
+            .map_err(GatewayError::from)
     }
 
-    pub async fn get_config(&self) -> GatewayResult<Config> {
-        retry(|| self.try_get_config()).await
+    // Checks that the api endpoint is reachable and serving requests
+    pub async fn ping_api(&self) -> GatewayResult<()> {
+        reqwest::Client::new()
+            .get(format!("{}/transfers", self.try_api_url()?))
+            .query(&[("offset", "0"), ("limit", "1")])
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
     }
 
-    pub async fn try_get_config(&self) -> GatewayResult<Config> {
+    pub async fn get_clock(&self) -> GatewayResult<Clock> {
         let data = self
-            .rpc
-            .get_account_data(&CONFIG_ADDRESS)
-            .await
-            .map_err(GatewayError::from)?;
-        Ok(*Config::try_from_bytes(&data).expect("Failed to parse config account"))
+            .get_program_account_data(sysvar::clock::ID, sysvar::ID, CLOCK_TTL)
+            .await?;
+        bincode::deserialize::<Clock>(&data)
+            .or(Err(GatewayError::FailedDeserialization(sysvar::clock::ID)))
+    }
+
+    pub async fn get_config(&self) -> GatewayResult<Config> {
+        let data = self
+            .get_program_account_data(CONFIG_ADDRESS, ore_api::ID, CONFIG_TTL)
+            .await?;
+        parse_account::<Config>(CONFIG_ADDRESS, &data)
     }
 
     pub async fn get_proof(&self, authority: Pubkey) -> GatewayResult<Proof> {
-        retry(|| self.try_get_proof(authority)).await
+        let address = proof_pubkey(authority);
+        let data = self
+            .get_program_account_data(address, ore_api::ID, ACCOUNT_TTL)
+            .await?;
+        parse_account::<Proof>(address, &data)
     }
 
     pub async fn get_proof_update(
@@ -86,155 +298,145 @@ impl Gateway {
         authority: Pubkey,
         challenge: [u8; 32],
     ) -> GatewayResult<Proof> {
-        loop {
-            match retry(|| self.try_get_proof(authority)).await {
-                Err(err) => return Err(err),
-                Ok(proof) => {
-                    if proof.challenge.ne(&challenge) {
-                        return Ok(proof);
-                    }
+        let address = proof_pubkey(authority);
+        let mut updates = self.account_subscribe(address);
+        let mut proof = self.get_proof(authority).await?;
+        while proof.challenge.eq(&challenge) {
+            let notification = match timeout(SUBSCRIPTION_POLL_INTERVAL, updates.next()).await {
+                Ok(Some(notification)) => Some(notification),
+                // The subscription is unavailable, so fall back to polling
+                Ok(None) => {
+                    async_std::task::sleep(POLL_INTERVAL).await;
+                    None
                 }
-            }
-            async_std::task::sleep(Duration::from_millis(1000)).await;
+                // Poll in case a notification was missed
+                Err(_) => None,
+            };
+            self.invalidate(&[address]);
+            proof = match notification.as_ref().and_then(account_notification_data) {
+                Some(data) => parse_account::<Proof>(address, &data)?,
+                None => self.get_proof(authority).await?,
+            };
         }
+        Ok(proof)
     }
 
-    pub async fn try_get_proof(&self, authority: Pubkey) -> GatewayResult<Proof> {
+    pub async fn get_escrow(&self, authority: Pubkey) -> GatewayResult<Escrow> {
+        let address = escrow_pubkey(authority);
         let data = self
-            .rpc
-            .get_account_data(&proof_pubkey(authority))
-            .await
-            .map_err(GatewayError::from)?;
-        Ok(*Proof::try_from_bytes(&data).expect("Failed to parse proof"))
+            .get_program_account_data(address, ore_relayer_api::ID, ACCOUNT_TTL)
+            .await?;
+        parse_account::<Escrow>(address, &data)
     }
 
-    pub async fn get_escrow(&self, authority: Pubkey) -> GatewayResult<Escrow> {
-        retry(|| self.try_get_escrow(authority)).await
+    /// The balance of a token account, in the token's base units and as a ui amount.
+    pub async fn get_token_balance(
+        &self,
+        address: Pubkey,
+        decimals: u8,
+    ) -> GatewayResult<UiTokenAmount> {
+        let data = self
+            .get_program_account_data(address, spl_token::id(), ACCOUNT_TTL)
+            .await?;
+        let token_account = spl_token::state::Account::unpack(&data)
+            .or(Err(GatewayError::FailedDeserialization(address)))?;
+        Ok(token_amount_to_ui_amount(token_account.amount, decimals))
     }
 
-    pub async fn try_get_escrow(&self, authority: Pubkey) -> GatewayResult<Escrow> {
-        let data = self
-            .rpc
-            .get_account_data(&escrow_pubkey(authority))
-            .await
-            .map_err(GatewayError::from)?;
-        Ok(*Escrow::try_from_bytes(&data).expect("Failed to parse escrow"))
+    /// The balance of an account in lamports. Accounts which do not exist have no balance.
+    pub async fn get_balance(&self, address: Pubkey) -> GatewayResult<u64> {
+        Ok(self
+            .get_account_cached(address, ACCOUNT_TTL)
+            .await?
+            .map(|account| account.lamports)
+            .unwrap_or(0))
     }
 
     pub async fn get_token_account(
         &self,
         pubkey: &Pubkey,
     ) -> GatewayResult<Option<UiTokenAccount>> {
-        retry(|| self.try_get_token_account(pubkey)).await
+        self.retry(|| self.try_get_token_account(pubkey)).await
     }
 
     pub async fn try_get_token_account(
         &self,
         pubkey: &Pubkey,
     ) -> GatewayResult<Option<UiTokenAccount>> {
-        self.rpc
+        self.rpc()?
             .get_token_account(pubkey)
             .await
             .map_err(GatewayError::from)
     }
 
     pub async fn get_proof_v1(&self, authority: Pubkey) -> GatewayResult<ore_api_v1::state::Proof> {
-        retry(|| self.try_get_proof_v1(authority)).await
+        let address = proof_v1_pubkey(authority);
+        let data = self
+            .get_program_account_data(address, ore_api_v1::ID, ACCOUNT_TTL)
+            .await?;
+        <ore_api_v1::state::Proof as ore_api_v1::utils::AccountDeserialize>::try_from_bytes(&data)
+            .copied()
+            .or(Err(GatewayError::FailedDeserialization(address)))
     }
 
-    pub async fn try_get_proof_v1(
+    // Fetches an account's data, verifying it is owned by the expected program
+    async fn get_program_account_data(
         &self,
-        authority: Pubkey,
-    ) -> GatewayResult<ore_api_v1::state::Proof> {
-        let data = self
-            .rpc
-            .get_account_data(&proof_v1_pubkey(authority))
-            .await
-            .map_err(GatewayError::from)?;
-        Ok(
-            *<ore_api_v1::state::Proof as ore_api_v1::utils::AccountDeserialize>::try_from_bytes(
-                &data,
-            )
-            .expect("Failed to parse proof"),
-        )
+        address: Pubkey,
+        program_id: Pubkey,
+        ttl: Duration,
+    ) -> GatewayResult<Vec<u8>> {
+        let account = self
+            .get_account_cached(address, ttl)
+            .await?
+            .ok_or(GatewayError::AccountNotFound)?;
+        if account.owner.ne(&program_id) {
+            return Err(GatewayError::InvalidAccountOwner {
+                address,
+                owner: account.owner,
+            });
+        }
+        Ok(account.data)
     }
 
     pub async fn get_latest_blockhash(&self) -> GatewayResult<Hash> {
-        retry(|| self.try_get_latest_blockhash()).await
+        self.retry(|| self.try_get_latest_blockhash()).await
     }
 
+    // Remembers the last block height each blockhash is valid for, so confirmation
+    // knows when a transaction can no longer land
     pub async fn try_get_latest_blockhash(&self) -> GatewayResult<Hash> {
-        self.rpc
-            .get_latest_blockhash()
-            .await
-            .map_err(GatewayError::from)
-    }
-
-    pub async fn confirm_signature(&self, sig: Signature) -> GatewayResult<Signature> {
-        // Confirm tx
-        for _ in 0..CONFIRM_RETRIES {
-            // Delay before confirming
-            async_std::task::sleep(Duration::from_millis(CONFIRM_DELAY)).await;
-
-            // Fetch transaction status
-            match self.rpc.get_signature_statuses(&[sig]).await {
-                Ok(signature_statuses) => {
-                    for signature_status in signature_statuses {
-                        if let Some(signature_status) = signature_status.as_ref() {
-                            if signature_status.confirmation_status.is_some() {
-                                if let Some(current_commitment) =
-                                    signature_status.confirmation_status.as_ref()
-                                {
-                                    match current_commitment {
-                                        TransactionConfirmationStatus::Processed => {}
-                                        TransactionConfirmationStatus::Confirmed
-                                        | TransactionConfirmationStatus::Finalized => {
-                                            log::info!("Confirmed: true");
-                                            return Ok(sig);
-                                        }
-                                    }
-                                }
-                            } else {
-                                log::info!("No status");
-                            }
-                        }
-                    }
-                }
-
-                // Handle confirmation errors
-                Err(err) => {
-                    log::error!("Error confirming: {:?}", err);
-                }
+        let res = self
+            .try_rpc_request(
+                "getLatestBlockhash",
+                &json!([{ "commitment": "confirmed" }]),
+            )
+            .await?;
+        let blockhash = res["value"]["blockhash"]
+            .as_str()
+            .and_then(|hash| hash.parse::<Hash>().ok())
+            .ok_or(GatewayError::Unknown("Invalid blockhash".to_string()))?;
+        if let Some(height) = res["value"]["lastValidBlockHeight"].as_u64() {
+            let mut heights = self.last_valid_block_heights.borrow_mut();
+            if heights.len().ge(&MAX_BLOCKHASHES) {
+                heights.clear();
             }
+            heights.insert(blockhash, height);
         }
-
-        return Err(GatewayError::TransactionTimeout);
-    }
-
-    // asserts that the token account is already initialized
-    pub async fn get_token_account_ore_from_pubkey(&self, pubkey: Pubkey) -> GatewayResult<Pubkey> {
-        let token_account_address = ore_token_account_address(pubkey);
-        self.assert_token_account_ore_exists(token_account_address)
-            .await
+        Ok(blockhash)
     }
 
-    // asserts that the token account is already initialized
-    async fn assert_token_account_ore_exists(&self, ata: Pubkey) -> GatewayResult<Pubkey> {
-        self.rpc
-            .get_token_account(&ata)
-            .await
-            .map_err(GatewayError::from)
-            .and_then(|maybe_some_token_account| {
-                // assert that ok(none) was not returned
-                maybe_some_token_account.ok_or(GatewayError::FailedAta)
-            })
-            .map(|_| ata)
+    pub async fn get_slot(&self) -> GatewayResult<u64> {
+        self.rpc_request("getSlot", json!([{ "commitment": "finalized" }]))
+            .await?
+            .as_u64()
+            .ok_or(GatewayError::Unknown("Invalid slot".to_string()))
     }
 
     // API
     pub async fn get_transfer(&self, sig: String) -> GatewayResult<Transfer> {
         match reqwest::Client::new()
-            .get(format!("{}/transfers/{}", self.api_url, sig))
+            .get(format!("{}/transfers/{}", self.try_api_url()?, sig))
             .send()
             .await
         {
@@ -259,7 +461,7 @@ impl Gateway {
         };
         log::info!("Query: {:?}", query);
         match reqwest::Client::new()
-            .get(format!("{}/transfers", &self.api_url))
+            .get(format!("{}/transfers", self.try_api_url()?))
             .query(&query)
             .send()
             .await
@@ -273,37 +475,44 @@ impl Gateway {
     }
 }
 
-pub async fn retry<F, Fut, T>(f: F) -> GatewayResult<T>
-where
-    F: Fn() -> Fut,
-    Fut: Future<Output = GatewayResult<T>>,
-{
-    const MAX_RETRIES: u32 = 8;
-    const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
-    const TIMEOUT: Duration = Duration::from_secs(8);
-    let mut backoff = INITIAL_BACKOFF;
-    for attempt in 0..MAX_RETRIES {
-        match timeout(TIMEOUT, f()).await {
-            Ok(Ok(result)) => return Ok(result),
-            Ok(Err(e)) if attempt < MAX_RETRIES - 1 => {
-                match e {
-                    GatewayError::AccountNotFound => return Err(e),
-                    _ => {
-                        async_std::task::sleep(backoff).await;
-                        backoff *= 2; // Exponential backoff
-                    }
-                }
-            }
-            Ok(Err(e)) => return Err(e),
-            Err(_) if attempt < MAX_RETRIES - 1 => {
-                async_std::task::sleep(backoff).await;
-                backoff *= 2; // Exponential backoff
-            }
-            Err(_) => return Err(GatewayError::RetryFailed),
-        }
+fn parse_account<T: AccountDeserialize + Copy>(address: Pubkey, data: &[u8]) -> GatewayResult<T> {
+    T::try_from_bytes(data)
+        .copied()
+        .or(Err(GatewayError::FailedDeserialization(address)))
+}
+
+// Reads the account data of an account notification
+fn account_notification_data(notification: &Value) -> Option<Vec<u8>> {
+    let data = notification["value"]["data"][0].as_str()?;
+    base64::engine::general_purpose::STANDARD.decode(data).ok()
+}
+
+// Reads the transaction result of a signature notification
+fn signature_notification_result(sig: Signature, notification: &Value) -> GatewayResult<Signature> {
+    let err = &notification["value"]["err"];
+    if err.is_null() {
+        return Ok(sig);
     }
+    Err(parse_transaction_error(err))
+}
 
-    Err(GatewayError::AccountNotFound)
+// Parses the program logs of a simulation
+fn parse_logs(logs: &Value) -> Vec<String> {
+    logs.as_array()
+        .map(|logs| {
+            logs.iter()
+                .filter_map(|log| log.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Parses a transaction error as returned by the rpc
+fn parse_transaction_error(err: &Value) -> GatewayError {
+    match serde_json::from_value::<TransactionError>(err.clone()) {
+        Ok(err) => err.into(),
+        Err(_) => GatewayError::Unknown(err.to_string()),
+    }
 }
 
 #[cached]
@@ -0,0 +1,412 @@
+use std::{collections::HashSet, str::FromStr};
+
+use base64::Engine;
+use ore_api::consts::{MINT_ADDRESS, TOKEN_DECIMALS};
+use percent_encoding::percent_decode_str;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_client_wasm::solana_sdk::{
+    pubkey::Pubkey, signature::Signature, transaction::VersionedTransaction,
+};
+use url::form_urlencoded;
+
+use super::{
+    history::parsed_instructions, ore_token_account_address, Gateway, GatewayError, GatewayResult,
+};
+use crate::utils::{format_ui_amount, parse_ui_amount};
+
+const SCHEME: &str = "solana:";
+
+// References are unique to a request, so only a few transactions should ever use one
+const MAX_REFERENCE_SIGNATURES: usize = 10;
+
+/// A Solana Pay request, parsed from a `solana:` url.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaymentRequest {
+    Transfer(TransferRequest),
+    Transaction(TransactionRequest),
+}
+
+/// Asks for a transfer of ORE. References are extra keys added to the transfer, so the
+/// recipient can find the transaction which paid them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransferRequest {
+    pub recipient: Pubkey,
+    pub amount: Option<u64>,
+    pub references: Vec<Pubkey>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+    pub memo: Option<String>,
+}
+
+/// Points to a merchant's endpoint, which builds the transaction for the payer to sign.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequest {
+    pub link: String,
+}
+
+/// The merchant's description of a transaction request.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct TransactionRequestInfo {
+    pub label: String,
+    pub icon: String,
+}
+
+#[derive(Deserialize)]
+struct TransactionRequestResponse {
+    transaction: String,
+    message: Option<String>,
+}
+
+impl TransferRequest {
+    pub fn to_url(&self) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("spl-token", &MINT_ADDRESS.to_string());
+        if let Some(amount) = self.amount {
+            query.append_pair("amount", &format_ui_amount(amount, TOKEN_DECIMALS));
+        }
+        for reference in &self.references {
+            query.append_pair("reference", &reference.to_string());
+        }
+        for (key, value) in [
+            ("label", &self.label),
+            ("message", &self.message),
+            ("memo", &self.memo),
+        ] {
+            if let Some(value) = value {
+                query.append_pair(key, value);
+            }
+        }
+        format!("{}{}?{}", SCHEME, self.recipient, query.finish())
+    }
+}
+
+/// Parses a `solana:` url. Transfer requests must be for ORE.
+pub fn parse_payment_url(url: &str) -> Result<PaymentRequest, String> {
+    let rest = url
+        .trim()
+        .strip_prefix(SCHEME)
+        .ok_or("Not a Solana Pay url".to_string())?;
+
+    // Transaction requests hold an https link, encoded if it has a query of its own
+    let link = percent_decode_str(rest).decode_utf8_lossy();
+    if link.starts_with("https://") {
+        return Ok(PaymentRequest::Transaction(TransactionRequest {
+            link: link.into_owned(),
+        }));
+    }
+
+    let (recipient, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let mut request = TransferRequest {
+        recipient: Pubkey::from_str(recipient).map_err(|_| "Invalid recipient".to_string())?,
+        ..Default::default()
+    };
+    let mut spl_token = None;
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        match key.as_ref() {
+            "amount" => {
+                request.amount = Some(
+                    parse_ui_amount(&value, TOKEN_DECIMALS).ok_or("Invalid amount".to_string())?,
+                );
+            }
+            "spl-token" => {
+                spl_token = Some(Pubkey::from_str(&value).map_err(|_| "Invalid token".to_string())?)
+            }
+            "reference" => request
+                .references
+                .push(Pubkey::from_str(&value).map_err(|_| "Invalid reference".to_string())?),
+            "label" => request.label = Some(value.into_owned()),
+            "message" => request.message = Some(value.into_owned()),
+            "memo" => request.memo = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+    if spl_token.ne(&Some(MINT_ADDRESS)) {
+        return Err("Only ORE payment requests are supported".to_string());
+    }
+    Ok(PaymentRequest::Transfer(request))
+}
+
+impl Gateway {
+    /// Returns the oldest transaction of any of the request's references which pays it, if
+    /// any. Like Solana Pay's validateTransfer, the recipient's token account must have
+    /// received at least the requested amount, and the transaction must carry the request's
+    /// memo. References and transactions which fail to load are skipped.
+    pub async fn find_transfer(&self, request: &TransferRequest) -> Option<Signature> {
+        let mut checked = HashSet::new();
+        for reference in &request.references {
+            let sigs = match self
+                .get_signatures_for_address(*reference, MAX_REFERENCE_SIGNATURES)
+                .await
+            {
+                Ok(sigs) => sigs,
+                Err(err) => {
+                    log::error!("Failed to fetch signatures for {}: {}", reference, err);
+                    continue;
+                }
+            };
+            for (_, sig) in sigs.iter().rev() {
+                if !checked.insert(sig.clone()) {
+                    continue;
+                }
+                match self.get_parsed_transaction(sig).await {
+                    Ok(tx) if is_valid_transfer(request, &tx) => return sig.parse().ok(),
+                    Ok(_) => {}
+                    Err(err) => log::error!("Failed to fetch transaction {}: {}", sig, err),
+                }
+            }
+        }
+        None
+    }
+
+    pub async fn get_transaction_request(
+        &self,
+        request: &TransactionRequest,
+    ) -> GatewayResult<TransactionRequestInfo> {
+        reqwest::Client::new()
+            .get(&request.link)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TransactionRequestInfo>()
+            .await
+            .map_err(GatewayError::from)
+    }
+
+    /// Asks the merchant for the transaction to sign. Returns the transaction and the
+    /// merchant's message about it, if any.
+    pub async fn post_transaction_request(
+        &self,
+        request: &TransactionRequest,
+        account: Pubkey,
+    ) -> GatewayResult<(VersionedTransaction, Option<String>)> {
+        let res = reqwest::Client::new()
+            .post(&request.link)
+            .json(&json!({ "account": account.to_string() }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TransactionRequestResponse>()
+            .await?;
+        let tx = base64::engine::general_purpose::STANDARD
+            .decode(res.transaction)
+            .ok()
+            .and_then(|buffer| bincode::deserialize::<VersionedTransaction>(&buffer).ok())
+            .ok_or(GatewayError::Unknown(
+                "Failed to decode transaction".to_string(),
+            ))?;
+        Ok((tx, res.message))
+    }
+}
+
+// Checks a jsonParsed transaction pays a transfer request
+fn is_valid_transfer(request: &TransferRequest, tx: &Value) -> bool {
+    if tx.is_null() || !tx["meta"]["err"].is_null() {
+        return false;
+    }
+    let received = ore_balance_change(tx, ore_token_account_address(request.recipient));
+    let is_paid = match request.amount {
+        Some(amount) => received.ge(&(amount as i128)),
+        None => received.gt(&0),
+    };
+    let has_memo = match &request.memo {
+        Some(memo) => parsed_instructions(tx).iter().any(|ix| {
+            ix["program"].as_str().eq(&Some("spl-memo"))
+                && ix["parsed"].as_str().eq(&Some(memo.as_str()))
+        }),
+        None => true,
+    };
+    is_paid && has_memo
+}
+
+// The change in ORE balance of a token account. Accounts created by the transaction have no
+// balance before it.
+fn ore_balance_change(tx: &Value, address: Pubkey) -> i128 {
+    let address = address.to_string();
+    let Some(index) = tx["transaction"]["message"]["accountKeys"]
+        .as_array()
+        .and_then(|keys| {
+            keys.iter()
+                .position(|key| key["pubkey"].as_str().eq(&Some(address.as_str())))
+        })
+    else {
+        return 0;
+    };
+    let mint = MINT_ADDRESS.to_string();
+    let mut change = 0;
+    for (key, sign) in [("preTokenBalances", -1), ("postTokenBalances", 1)] {
+        for balance in tx["meta"][key].as_array().into_iter().flatten() {
+            if balance["accountIndex"].as_u64().ne(&Some(index as u64))
+                || balance["mint"].as_str().ne(&Some(mint.as_str()))
+            {
+                continue;
+            }
+            if let Some(amount) = balance["uiTokenAmount"]["amount"]
+                .as_str()
+                .and_then(|amount| amount.parse::<i128>().ok())
+            {
+                change += sign * amount;
+            }
+        }
+    }
+    change
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ore(amount: u64) -> u64 {
+        amount * 10u64.pow(TOKEN_DECIMALS as u32)
+    }
+
+    fn transfer(url: &str) -> Result<TransferRequest, String> {
+        match parse_payment_url(url)? {
+            PaymentRequest::Transfer(request) => Ok(request),
+            PaymentRequest::Transaction(_) => Err("Not a transfer request".to_string()),
+        }
+    }
+
+    #[test]
+    fn parses_recipient_only() {
+        let recipient = Pubkey::new_unique();
+        let url = format!("solana:{}?spl-token={}", recipient, MINT_ADDRESS);
+        assert_eq!(
+            transfer(&url),
+            Ok(TransferRequest {
+                recipient,
+                ..Default::default()
+            })
+        );
+        assert!(transfer(&format!("solana:{}", recipient)).is_err());
+    }
+
+    #[test]
+    fn parses_amounts_with_decimals() {
+        let recipient = Pubkey::new_unique();
+        let amount = |amount: &str| {
+            transfer(&format!(
+                "solana:{}?amount={}&spl-token={}",
+                recipient, amount, MINT_ADDRESS
+            ))
+            .map(|request| request.amount)
+        };
+        assert_eq!(amount("1"), Ok(Some(ore(1))));
+        assert_eq!(amount("1.5"), Ok(Some(ore(15) / 10)));
+        assert_eq!(amount("0.1"), Ok(Some(ore(1) / 10)));
+        assert_eq!(amount("0.00000000001"), Ok(Some(1)));
+    }
+
+    #[test]
+    fn parses_multiple_references() {
+        let recipient = Pubkey::new_unique();
+        let references = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let url = format!(
+            "solana:{}?spl-token={}&reference={}&reference={}",
+            recipient, MINT_ADDRESS, references[0], references[1]
+        );
+        assert_eq!(transfer(&url).unwrap().references, references);
+    }
+
+    #[test]
+    fn round_trips_label_message_and_memo() {
+        let request = TransferRequest {
+            recipient: Pubkey::new_unique(),
+            amount: Some(ore(1234) / 1000),
+            references: vec![Pubkey::new_unique()],
+            label: Some("Coffee & Co".to_string()),
+            message: Some("Thanks! 100% + more = ☕".to_string()),
+            memo: Some("order#42?a=b".to_string()),
+        };
+        assert_eq!(transfer(&request.to_url()), Ok(request));
+    }
+
+    #[test]
+    fn decodes_plus_and_escapes_in_values() {
+        let url = format!(
+            "solana:{}?spl-token={}&label=Coffee+Shop&message=Hi%20there%21&memo=%E2%98%95",
+            Pubkey::new_unique(),
+            MINT_ADDRESS
+        );
+        let request = transfer(&url).unwrap();
+        assert_eq!(request.label.as_deref(), Some("Coffee Shop"));
+        assert_eq!(request.message.as_deref(), Some("Hi there!"));
+        assert_eq!(request.memo.as_deref(), Some("☕"));
+    }
+
+    #[test]
+    fn requires_the_ore_token() {
+        let recipient = Pubkey::new_unique();
+        assert!(transfer(&format!("solana:{}?spl-token={}", recipient, MINT_ADDRESS)).is_ok());
+        assert_eq!(
+            transfer(&format!(
+                "solana:{}?spl-token={}",
+                recipient,
+                Pubkey::new_unique()
+            )),
+            Err("Only ORE payment requests are supported".to_string())
+        );
+        assert_eq!(
+            transfer(&format!("solana:{}?spl-token=ore", recipient)),
+            Err("Invalid token".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_bad_pubkeys() {
+        let recipient = Pubkey::new_unique();
+        assert_eq!(
+            transfer(&format!("solana:not-a-key?spl-token={}", MINT_ADDRESS)),
+            Err("Invalid recipient".to_string())
+        );
+        assert_eq!(
+            transfer(&format!(
+                "solana:{}?spl-token={}&reference=0OIl",
+                recipient, MINT_ADDRESS
+            )),
+            Err("Invalid reference".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_bad_amounts() {
+        let recipient = Pubkey::new_unique();
+        for amount in [
+            "",
+            "-1",
+            "1e3",
+            "1,5",
+            "NaN",
+            "inf",
+            "0.000000000001",
+            "1.2.3",
+        ] {
+            assert_eq!(
+                transfer(&format!(
+                    "solana:{}?amount={}&spl-token={}",
+                    recipient, amount, MINT_ADDRESS
+                )),
+                Err("Invalid amount".to_string()),
+                "{}",
+                amount
+            );
+        }
+    }
+
+    #[test]
+    fn parses_transaction_requests() {
+        assert_eq!(
+            parse_payment_url("solana:https%3A%2F%2Fexample.com%2Fpay%3Fid%3D1%26x%3Da%2Bb"),
+            Ok(PaymentRequest::Transaction(TransactionRequest {
+                link: "https://example.com/pay?id=1&x=a+b".to_string()
+            }))
+        );
+        assert_eq!(
+            parse_payment_url("solana:https://example.com/pay"),
+            Ok(PaymentRequest::Transaction(TransactionRequest {
+                link: "https://example.com/pay".to_string()
+            }))
+        );
+        assert!(parse_payment_url("bitcoin:abc").is_err());
+    }
+}
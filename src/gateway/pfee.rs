@@ -1,39 +1,262 @@
-use serde_json::{json, Value};
+use std::{fmt, io, str::FromStr};
 
-use super::RPC_URL;
+use futures::future::{FutureExt, LocalBoxFuture};
+use ore_api::consts::{BUS_ADDRESSES, TREASURY_ADDRESS};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 
-pub async fn get_recent_priority_fee_estimate(treasury: bool) -> u64 {
-    let http_client = reqwest::Client::new();
-    let mut ore_addresses: Vec<String> = vec![ore_api::id().to_string()];
-    if treasury {
-        ore_addresses.push(ore_api::consts::TREASURY_ADDRESS.to_string());
+use super::{treasury_tokens_pubkey, Gateway, GatewayError, GatewayResult};
+
+/// How much priority to bid for, relative to recent fees paid on the network.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum FeeLevel {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl FeeLevel {
+    // Percentile of recent fees to pay
+    fn percentile(&self) -> usize {
+        match self {
+            FeeLevel::Low => 25,
+            FeeLevel::Medium => 50,
+            FeeLevel::High => 75,
+        }
+    }
+}
+
+/// The user's priority fee setting. Fees are in microlamports per compute unit.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PriorityFee {
+    Estimate(FeeLevel),
+    Fixed(u64),
+}
+
+impl Default for PriorityFee {
+    fn default() -> Self {
+        PriorityFee::Estimate(FeeLevel::default())
+    }
+}
+
+impl fmt::Display for PriorityFee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PriorityFee::Estimate(FeeLevel::Low) => write!(f, "Low"),
+            PriorityFee::Estimate(FeeLevel::Medium) => write!(f, "Medium"),
+            PriorityFee::Estimate(FeeLevel::High) => write!(f, "High"),
+            PriorityFee::Fixed(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl FromStr for PriorityFee {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Low" => Ok(PriorityFee::Estimate(FeeLevel::Low)),
+            "Medium" => Ok(PriorityFee::Estimate(FeeLevel::Medium)),
+            "High" => Ok(PriorityFee::Estimate(FeeLevel::High)),
+            "Custom" => Ok(PriorityFee::Fixed(0)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown priority fee",
+            )),
+        }
+    }
+}
+
+/// The transaction a fee is being estimated for. Each action bids against the accounts
+/// it write locks, and caps its fee so a congested network can't drain the user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeAction {
+    Claim,
+    LookupTable,
+    Migrate,
+    Mine,
+    Open,
+    Send,
+    Stake,
+    TopUp,
+    Upgrade,
+}
+
+impl FeeAction {
+    /// The highest fee the action will pay, in microlamports per compute unit.
+    pub fn max_fee(&self) -> u64 {
+        match self {
+            // Mining submits a transaction every minute, so fees add up quickly
+            FeeAction::Mine => 100_000,
+            FeeAction::LookupTable | FeeAction::Open | FeeAction::Migrate | FeeAction::TopUp => {
+                200_000
+            }
+            FeeAction::Claim | FeeAction::Send | FeeAction::Stake | FeeAction::Upgrade => 1_000_000,
+        }
+    }
+
+    // The contended accounts the action write locks
+    fn accounts(&self) -> Vec<Pubkey> {
+        match self {
+            FeeAction::Mine => BUS_ADDRESSES.to_vec(),
+            FeeAction::Claim | FeeAction::Stake | FeeAction::Upgrade => {
+                vec![TREASURY_ADDRESS, treasury_tokens_pubkey()]
+            }
+            FeeAction::LookupTable
+            | FeeAction::Migrate
+            | FeeAction::Open
+            | FeeAction::Send
+            | FeeAction::TopUp => vec![],
+        }
+    }
+}
+
+/// Estimates the priority fee, in microlamports per compute unit, needed to land a
+/// transaction which write locks the given accounts.
+pub trait FeeEstimator {
+    /// The rpc method the estimator calls, used to remember which rpcs don't support it.
+    fn name(&self) -> &'static str;
+
+    fn estimate<'a>(
+        &'a self,
+        gateway: &'a Gateway,
+        accounts: &'a [Pubkey],
+    ) -> LocalBoxFuture<'a, GatewayResult<u64>>;
+}
+
+/// Estimates fees from the standard getRecentPrioritizationFees rpc method, which every
+/// rpc supports. Takes a percentile of the fees paid over the last 150 slots.
+pub struct RecentPrioritizationFees(pub FeeLevel);
+
+impl FeeEstimator for RecentPrioritizationFees {
+    fn name(&self) -> &'static str {
+        "getRecentPrioritizationFees"
     }
 
-    let req = json!({
-        "jsonrpc": "2.0",
-        "id": "priority-fee-estimate",
-        "method": "getPriorityFeeEstimate",
-        "params": [{
-            "accountKeys": ore_addresses,
-            "options": {
-                "recommended": true
+    fn estimate<'a>(
+        &'a self,
+        gateway: &'a Gateway,
+        accounts: &'a [Pubkey],
+    ) -> LocalBoxFuture<'a, GatewayResult<u64>> {
+        async move {
+            let accounts: Vec<String> = accounts.iter().map(|a| a.to_string()).collect();
+            let res = gateway
+                .try_rpc_request(self.name(), &json!([accounts]))
+                .await?;
+            let mut fees: Vec<u64> = res
+                .as_array()
+                .ok_or(GatewayError::Unknown(
+                    "Invalid prioritization fees".to_string(),
+                ))?
+                .iter()
+                .filter_map(|sample| sample["prioritizationFee"].as_u64())
+                .filter(|fee| fee.gt(&0))
+                .collect();
+            if fees.is_empty() {
+                return Ok(0);
             }
-        }]
-    });
-
-    if let Ok(res) = http_client
-        .post(RPC_URL.to_string())
-        .json(&req)
-        .send()
-        .await
-    {
-        if let Ok(res) = res.json::<Value>().await {
-            return res["result"]["priorityFeeEstimate"]
+            fees.sort_unstable();
+            let index = (fees.len() - 1) * self.0.percentile() / 100;
+            Ok(fees[index])
+        }
+        .boxed_local()
+    }
+}
+
+/// Estimates fees from Helius's getPriorityFeeEstimate rpc extension.
+pub struct HeliusPriorityFeeEstimate(pub FeeLevel);
+
+impl FeeEstimator for HeliusPriorityFeeEstimate {
+    fn name(&self) -> &'static str {
+        "getPriorityFeeEstimate"
+    }
+
+    fn estimate<'a>(
+        &'a self,
+        gateway: &'a Gateway,
+        accounts: &'a [Pubkey],
+    ) -> LocalBoxFuture<'a, GatewayResult<u64>> {
+        async move {
+            let mut account_keys = vec![ore_api::id().to_string()];
+            account_keys.extend(accounts.iter().map(|a| a.to_string()));
+            let priority_level = match self.0 {
+                FeeLevel::Low => "Low",
+                FeeLevel::Medium => "Medium",
+                FeeLevel::High => "High",
+            };
+            let res = gateway
+                .try_rpc_request(
+                    self.name(),
+                    &json!([{
+                        "accountKeys": account_keys,
+                        "options": {
+                            "priorityLevel": priority_level
+                        }
+                    }]),
+                )
+                .await?;
+            res["priorityFeeEstimate"]
                 .as_f64()
                 .map(|fee| fee as u64)
-                .unwrap_or(0);
+                .ok_or(GatewayError::Unknown(
+                    "Invalid priority fee estimate".to_string(),
+                ))
         }
+        .boxed_local()
     }
+}
+
+/// A fee set by the user.
+pub struct FixedFee(pub u64);
 
-    0
+impl FeeEstimator for FixedFee {
+    fn name(&self) -> &'static str {
+        "fixedFee"
+    }
+
+    fn estimate<'a>(
+        &'a self,
+        _gateway: &'a Gateway,
+        _accounts: &'a [Pubkey],
+    ) -> LocalBoxFuture<'a, GatewayResult<u64>> {
+        async move { Ok(self.0) }.boxed_local()
+    }
+}
+
+impl Gateway {
+    /// Returns the priority fee to pay for an action, capped at the action's max fee.
+    /// Estimators are tried in order, skipping those the rpc has rejected before, and if
+    /// every estimate fails no priority fee is paid.
+    pub async fn get_priority_fee(&self, priority_fee: PriorityFee, action: FeeAction) -> u64 {
+        let estimators: Vec<Box<dyn FeeEstimator>> = match priority_fee {
+            PriorityFee::Fixed(fee) => vec![Box::new(FixedFee(fee))],
+            PriorityFee::Estimate(level) => vec![
+                Box::new(HeliusPriorityFeeEstimate(level)),
+                Box::new(RecentPrioritizationFees(level)),
+            ],
+        };
+        let accounts = action.accounts();
+        for estimator in estimators {
+            let key = (
+                self.rpc_url().unwrap_or_default().to_string(),
+                estimator.name(),
+            );
+            if self.unsupported_fee_estimators.borrow().contains(&key) {
+                continue;
+            }
+            match estimator.estimate(self, &accounts).await {
+                Ok(fee) => return fee.min(action.max_fee()),
+                Err(err) => {
+                    log::info!("Fee estimate {} failed: {}", estimator.name(), err);
+                    // The rpc served the request, so it doesn't support the method
+                    if let GatewayError::RpcError { .. } = err {
+                        self.unsupported_fee_estimators.borrow_mut().insert(key);
+                    }
+                }
+            }
+        }
+        0
+    }
 }
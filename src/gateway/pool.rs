@@ -0,0 +1,181 @@
+use std::cell::{Cell, RefCell};
+
+use solana_client_wasm::WasmClient;
+use web_time::{Duration, Instant};
+
+use super::GatewayError;
+
+/// How long an endpoint is skipped after it rate limits us.
+const RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// How long an endpoint is skipped after it fails to respond.
+const UNAVAILABLE_COOLDOWN: Duration = Duration::from_secs(15);
+
+/// Weight given to the newest sample in the latency moving average.
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+/// An rpc endpoint and the health stats collected while using it.
+pub struct RpcEndpoint {
+    pub url: String,
+    pub client: WasmClient,
+    health: RefCell<EndpointHealth>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct EndpointHealth {
+    requests: u64,
+    errors: u64,
+    latency_ms: Option<f64>,
+    last_error: Option<GatewayError>,
+    cooldown_until: Option<Instant>,
+}
+
+/// A snapshot of an endpoint's health, suitable for display.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EndpointStatus {
+    pub url: String,
+    pub active: bool,
+    pub requests: u64,
+    pub errors: u64,
+    pub latency_ms: Option<f64>,
+    pub last_error: Option<GatewayError>,
+    pub cooldown_secs: Option<u64>,
+}
+
+impl EndpointStatus {
+    pub fn error_rate(&self) -> f64 {
+        if self.requests.eq(&0) {
+            0.0
+        } else {
+            self.errors as f64 / self.requests as f64
+        }
+    }
+}
+
+/// An ordered pool of rpc endpoints. Requests go to the first endpoint in the list
+/// which is not cooling down, so the pool fails over to the next endpoint whenever
+/// the preferred one is unavailable or rate limited.
+pub struct RpcPool {
+    endpoints: Vec<RpcEndpoint>,
+    active: Cell<usize>,
+}
+
+impl RpcPool {
+    pub fn new(urls: Vec<String>) -> Self {
+        RpcPool {
+            endpoints: urls
+                .into_iter()
+                .map(|url| RpcEndpoint {
+                    client: WasmClient::new(&url),
+                    url,
+                    health: RefCell::new(EndpointHealth::default()),
+                })
+                .collect(),
+            active: Cell::new(0),
+        }
+    }
+
+    pub fn urls(&self) -> Vec<String> {
+        self.endpoints.iter().map(|e| e.url.clone()).collect()
+    }
+
+    /// Returns the index of the endpoint requests should currently be sent to, or none if
+    /// the pool is empty.
+    pub fn current_index(&self) -> Option<usize> {
+        let now = Instant::now();
+        let index = self
+            .endpoints
+            .iter()
+            .position(|e| match e.health.borrow().cooldown_until {
+                Some(until) => until.le(&now),
+                None => true,
+            })
+            .or_else(|| {
+                // Every endpoint is cooling down, so use the one that recovers first
+                self.endpoints
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, e)| e.health.borrow().cooldown_until)
+                    .map(|(i, _)| i)
+            })?;
+        if index.ne(&self.active.get()) {
+            log::debug!("Switching rpc endpoint: {}", self.endpoints[index].url);
+            self.active.set(index);
+        }
+        Some(index)
+    }
+
+    pub fn current(&self) -> Option<&RpcEndpoint> {
+        self.current_index().map(|index| &self.endpoints[index])
+    }
+
+    pub fn record_success(&self, index: usize, latency: Duration) {
+        if let Some(endpoint) = self.endpoints.get(index) {
+            let mut health = endpoint.health.borrow_mut();
+            health.requests += 1;
+            health.cooldown_until = None;
+            health.record_latency(latency);
+        }
+    }
+
+    /// Records a failed request. Returns true if the endpoint was put into cooldown,
+    /// meaning the next request will be routed to a different endpoint.
+    pub fn record_failure(&self, index: usize, err: GatewayError, latency: Duration) -> bool {
+        let Some(endpoint) = self.endpoints.get(index) else {
+            return false;
+        };
+        let mut health = endpoint.health.borrow_mut();
+        health.requests += 1;
+        health.errors += 1;
+        health.record_latency(latency);
+        let cooldown = match err {
+            GatewayError::RateLimited => Some(RATE_LIMIT_COOLDOWN),
+            GatewayError::NetworkUnavailable | GatewayError::TimeoutError => {
+                Some(UNAVAILABLE_COOLDOWN)
+            }
+            _ => None,
+        };
+        health.last_error = Some(err);
+        match cooldown {
+            Some(cooldown) => {
+                health.cooldown_until = Some(Instant::now() + cooldown);
+                self.endpoints.len().gt(&1)
+            }
+            None => false,
+        }
+    }
+
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        let now = Instant::now();
+        let active = self.active.get();
+        self.endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let health = e.health.borrow();
+                EndpointStatus {
+                    url: e.url.clone(),
+                    active: i.eq(&active),
+                    requests: health.requests,
+                    errors: health.errors,
+                    latency_ms: health.latency_ms,
+                    last_error: health.last_error.clone(),
+                    cooldown_secs: health
+                        .cooldown_until
+                        .filter(|until| until.gt(&now))
+                        .map(|until| until.duration_since(now).as_secs()),
+                }
+            })
+            .collect()
+    }
+}
+
+impl EndpointHealth {
+    fn record_latency(&mut self, latency: Duration) {
+        let sample = latency.as_secs_f64() * 1000.0;
+        self.latency_ms = Some(match self.latency_ms {
+            Some(avg) => avg + LATENCY_SMOOTHING * (sample - avg),
+            None => sample,
+        });
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    pin::Pin,
+    rc::{Rc, Weak},
+    task::{Context, Poll},
+};
+
+use futures::{
+    channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+    Stream,
+};
+use serde_json::{json, Value};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{CloseEvent, MessageEvent, WebSocket};
+use web_time::Duration;
+
+/// Delay before the first reconnect attempt. Doubles with every failed attempt.
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound on the delay between reconnect attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// A websocket connection to the pubsub endpoint of an rpc in the pool. The socket is
+/// opened lazily on the first subscription, and if it drops, it is reopened with backoff
+/// and every open subscription is resubscribed. Endpoints are tried in order, so a socket
+/// which fails to open fails over to the next endpoint, like the rpc pool.
+pub struct PubSub {
+    inner: Rc<RefCell<PubSubInner>>,
+}
+
+struct PubSubInner {
+    urls: Vec<String>,
+    active: usize,
+    socket: Option<WebSocket>,
+    connected: bool,
+    closed: bool,
+    reconnect_attempts: u32,
+    next_id: u64,
+    subscriptions: HashMap<u64, Subscription>,
+    requests: HashMap<u64, (u64, &'static str)>,
+}
+
+struct Subscription {
+    method: &'static str,
+    params: Value,
+    sender: UnboundedSender<Value>,
+    server_id: Option<u64>,
+}
+
+/// A stream of notifications for a single subscription. The subscription is cancelled
+/// when the stream is dropped. The stream ends if the rpc rejects the subscription,
+/// in which case callers should fall back to polling.
+pub struct PubSubStream {
+    key: u64,
+    receiver: UnboundedReceiver<Value>,
+    inner: Weak<RefCell<PubSubInner>>,
+}
+
+impl PubSub {
+    pub fn new(urls: Vec<String>) -> Self {
+        PubSub {
+            inner: Rc::new(RefCell::new(PubSubInner {
+                urls,
+                active: 0,
+                socket: None,
+                connected: false,
+                closed: false,
+                reconnect_attempts: 0,
+                next_id: 0,
+                subscriptions: HashMap::new(),
+                requests: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn subscribe(&self, method: &'static str, params: Value) -> PubSubStream {
+        let (sender, receiver) = unbounded();
+        let mut inner = self.inner.borrow_mut();
+        let key = inner.next_id();
+        inner.subscriptions.insert(
+            key,
+            Subscription {
+                method,
+                params,
+                sender,
+                server_id: None,
+            },
+        );
+        if inner.connected {
+            inner.send_subscribe(key);
+        } else if inner.socket.is_none() {
+            drop(inner);
+            connect(&self.inner);
+        }
+        PubSubStream {
+            key,
+            receiver,
+            inner: Rc::downgrade(&self.inner),
+        }
+    }
+}
+
+impl Drop for PubSub {
+    fn drop(&mut self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.closed = true;
+        if let Some(socket) = inner.socket.take() {
+            socket.close().ok();
+        }
+    }
+}
+
+impl PubSubInner {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn send(&self, msg: Value) {
+        if let Some(socket) = self.socket.as_ref() {
+            if let Err(err) = socket.send_with_str(&msg.to_string()) {
+                log::error!("Failed to send pubsub message: {:?}", err);
+            }
+        }
+    }
+
+    fn send_subscribe(&mut self, key: u64) {
+        let Some(subscription) = self.subscriptions.get(&key) else {
+            return;
+        };
+        let method = subscription.method;
+        let params = subscription.params.clone();
+        let id = self.next_id();
+        self.requests.insert(id, (key, method));
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }));
+    }
+
+    fn send_unsubscribe(&mut self, method: &'static str, server_id: u64) {
+        if !self.connected {
+            return;
+        }
+        let id = self.next_id();
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method.replace("Subscribe", "Unsubscribe"),
+            "params": [server_id],
+        }));
+    }
+
+    fn url(&self) -> Option<&String> {
+        self.urls.get(self.active)
+    }
+
+    fn on_open(&mut self) {
+        log::debug!(
+            "Pubsub connected: {}",
+            self.url().cloned().unwrap_or_default()
+        );
+        self.connected = true;
+        self.reconnect_attempts = 0;
+        let keys: Vec<u64> = self.subscriptions.keys().copied().collect();
+        for key in keys {
+            self.send_subscribe(key);
+        }
+    }
+
+    fn on_message(&mut self, msg: Value) {
+        // Notifications carry the server's subscription id
+        if msg["method"].is_string() {
+            let Some(server_id) = msg["params"]["subscription"].as_u64() else {
+                return;
+            };
+            if let Some(subscription) = self
+                .subscriptions
+                .values()
+                .find(|s| s.server_id.eq(&Some(server_id)))
+            {
+                subscription
+                    .sender
+                    .unbounded_send(msg["params"]["result"].clone())
+                    .ok();
+            }
+            return;
+        }
+
+        // Responses to subscribe requests carry the id of the request
+        let Some((key, method)) = msg["id"].as_u64().and_then(|id| self.requests.remove(&id))
+        else {
+            return;
+        };
+        match msg["result"].as_u64() {
+            Some(server_id) => match self.subscriptions.get_mut(&key) {
+                Some(subscription) => subscription.server_id = Some(server_id),
+                // The stream was dropped while the request was in flight
+                None => self.send_unsubscribe(method, server_id),
+            },
+            None => {
+                // Dropping the sender ends the stream, so the caller can fall back to polling
+                log::error!("Pubsub {} failed: {}", method, msg["error"]);
+                self.subscriptions.remove(&key);
+            }
+        }
+    }
+
+    fn on_close(&mut self) {
+        // The socket never opened, so try the next endpoint
+        if !self.connected && self.urls.len().gt(&1) {
+            self.active = (self.active + 1) % self.urls.len();
+            log::debug!("Switching pubsub endpoint: {}", self.urls[self.active]);
+        }
+        self.connected = false;
+        self.socket = None;
+        self.requests.clear();
+        for subscription in self.subscriptions.values_mut() {
+            subscription.server_id = None;
+        }
+    }
+
+    fn reconnect_delay(&mut self) -> Duration {
+        let delay = RECONNECT_DELAY
+            .saturating_mul(2u32.saturating_pow(self.reconnect_attempts))
+            .min(MAX_RECONNECT_DELAY);
+        self.reconnect_attempts = self.reconnect_attempts.saturating_add(1);
+        delay
+    }
+}
+
+impl Stream for PubSubStream {
+    type Item = Value;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+impl Drop for PubSubStream {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.upgrade() {
+            let mut inner = inner.borrow_mut();
+            if let Some(subscription) = inner.subscriptions.remove(&self.key) {
+                if let Some(server_id) = subscription.server_id {
+                    inner.send_unsubscribe(subscription.method, server_id);
+                }
+            }
+        }
+    }
+}
+
+fn connect(inner: &Rc<RefCell<PubSubInner>>) {
+    let url = inner.borrow().url().cloned();
+    let Some(url) = url else {
+        // Without an endpoint, end every stream so callers fall back to polling
+        inner.borrow_mut().subscriptions.clear();
+        return;
+    };
+    let socket = match WebSocket::new(&url) {
+        Ok(socket) => socket,
+        Err(err) => {
+            log::error!("Failed to open pubsub socket: {:?}", err);
+            inner.borrow_mut().on_close();
+            schedule_reconnect(inner);
+            return;
+        }
+    };
+
+    // On open
+    let weak = Rc::downgrade(inner);
+    socket.set_onopen(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn()>::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                inner.borrow_mut().on_open();
+            }
+        })
+        .into_js_value(),
+    )));
+
+    // On message
+    let weak = Rc::downgrade(inner);
+    socket.set_onmessage(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
+            let Some(inner) = weak.upgrade() else {
+                return;
+            };
+            if let Some(msg) = e
+                .data()
+                .as_string()
+                .and_then(|data| serde_json::from_str::<Value>(&data).ok())
+            {
+                inner.borrow_mut().on_message(msg);
+            }
+        })
+        .into_js_value(),
+    )));
+
+    // On close
+    let weak = Rc::downgrade(inner);
+    socket.set_onclose(Some(&js_sys::Function::unchecked_from_js(
+        Closure::<dyn Fn(CloseEvent)>::new(move |e: CloseEvent| {
+            let Some(inner) = weak.upgrade() else {
+                return;
+            };
+            log::debug!("Pubsub disconnected: {}", e.code());
+            inner.borrow_mut().on_close();
+            schedule_reconnect(&inner);
+        })
+        .into_js_value(),
+    )));
+
+    inner.borrow_mut().socket = Some(socket);
+}
+
+fn schedule_reconnect(inner: &Rc<RefCell<PubSubInner>>) {
+    let delay = {
+        let mut inner = inner.borrow_mut();
+        if inner.closed || inner.subscriptions.is_empty() {
+            // Reconnect lazily on the next subscription
+            return;
+        }
+        inner.reconnect_delay()
+    };
+    let weak = Rc::downgrade(inner);
+    wasm_bindgen_futures::spawn_local(async move {
+        async_std::task::sleep(delay).await;
+        if let Some(inner) = weak.upgrade() {
+            let reconnect = {
+                let inner = inner.borrow();
+                !inner.closed && inner.socket.is_none()
+            };
+            if reconnect {
+                connect(&inner);
+            }
+        }
+    });
+}
+
+// The rpc port of solana-test-validator
+const LOCAL_RPC_PORT: u16 = 8899;
+
+/// Derives the pubsub url of an rpc. The websocket of a local validator listens on the port
+/// after its default rpc port, and every other rpc serves both on the same port.
+pub fn pubsub_url(rpc_url: &str) -> String {
+    let Ok(mut url) = url::Url::parse(rpc_url) else {
+        return rpc_url.to_string();
+    };
+    let scheme = match url.scheme() {
+        "https" => "wss",
+        _ => "ws",
+    };
+    url.set_scheme(scheme).ok();
+    if url.port().eq(&Some(LOCAL_RPC_PORT)) {
+        url.set_port(Some(LOCAL_RPC_PORT + 1)).ok();
+    }
+    url.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pubsub_url_keeps_the_rpc_port() {
+        assert_eq!(
+            pubsub_url("https://api.mainnet-beta.solana.com"),
+            "wss://api.mainnet-beta.solana.com/"
+        );
+        assert_eq!(
+            pubsub_url("https://rpc.example.com:8080/key"),
+            "wss://rpc.example.com:8080/key"
+        );
+        assert_eq!(
+            pubsub_url("http://proxy.example.com:443"),
+            "ws://proxy.example.com:443/"
+        );
+    }
+
+    #[test]
+    fn pubsub_url_of_a_local_validator() {
+        assert_eq!(pubsub_url("http://localhost:8899"), "ws://localhost:8900/");
+        assert_eq!(pubsub_url("http://127.0.0.1:8899"), "ws://127.0.0.1:8900/");
+    }
+}
@@ -0,0 +1,99 @@
+use base64::Engine;
+use serde_json::json;
+use solana_client_wasm::solana_sdk::{
+    compute_budget::{self, ComputeBudgetInstruction},
+    message::VersionedMessage,
+    transaction::VersionedTransaction,
+};
+
+use crate::tx::MAX_COMPUTE_UNITS;
+
+use super::{parse_logs, parse_transaction_error, Gateway, GatewayError, GatewayResult};
+
+/// Headroom added on top of the simulated compute units, as a percentage.
+const COMPUTE_UNIT_MARGIN: u64 = 10;
+
+/// Minimum headroom added on top of the simulated compute units.
+const MIN_COMPUTE_UNIT_MARGIN: u64 = 1_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Simulation {
+    pub units_consumed: u64,
+    pub logs: Vec<String>,
+}
+
+impl Gateway {
+    /// Simulates a transaction without requiring signatures. The blockhash is replaced
+    /// by the rpc, so this can run before the transaction is ready to sign.
+    pub async fn simulate(&self, tx: &VersionedTransaction) -> GatewayResult<Simulation> {
+        let bytes = bincode::serialize(tx).or(Err(GatewayError::Unknown(
+            "Failed to serialize transaction".to_string(),
+        )))?;
+        let params = json!([
+            base64::engine::general_purpose::STANDARD.encode(bytes),
+            {
+                "encoding": "base64",
+                "sigVerify": false,
+                "replaceRecentBlockhash": true,
+                "commitment": "confirmed",
+            }
+        ]);
+        let res = self.rpc_request("simulateTransaction", params).await?;
+        let value = &res["value"];
+        let logs = parse_logs(&value["logs"]);
+        if !value["err"].is_null() {
+            log::debug!("Simulation logs: {:?}", logs);
+            return Err(GatewayError::SimulationFailed {
+                error: Box::new(parse_transaction_error(&value["err"]).with_message(&tx.message)),
+                logs,
+            });
+        }
+        Ok(Simulation {
+            units_consumed: value["unitsConsumed"].as_u64().unwrap_or(0),
+            logs,
+        })
+    }
+
+    /// Simulates a transaction and sizes its compute unit limit to the units consumed plus
+    /// a margin. Fails if the transaction would fail onchain.
+    pub async fn simulate_compute_units(
+        &self,
+        tx: &mut VersionedTransaction,
+    ) -> GatewayResult<u32> {
+        set_compute_unit_limit(&mut tx.message, MAX_COMPUTE_UNITS);
+        let simulation = self.simulate(tx).await?;
+        let margin = simulation
+            .units_consumed
+            .saturating_mul(COMPUTE_UNIT_MARGIN)
+            .saturating_div(100)
+            .max(MIN_COMPUTE_UNIT_MARGIN);
+        let units = simulation
+            .units_consumed
+            .saturating_add(margin)
+            .min(MAX_COMPUTE_UNITS as u64) as u32;
+        log::debug!(
+            "Simulated {} compute units, requesting {}",
+            simulation.units_consumed,
+            units
+        );
+        set_compute_unit_limit(&mut tx.message, units);
+        Ok(units)
+    }
+}
+
+// Rewrites the compute unit limit instruction of a message, if it has one
+fn set_compute_unit_limit(message: &mut VersionedMessage, units: u32) {
+    let data = ComputeBudgetInstruction::set_compute_unit_limit(units).data;
+    let (account_keys, instructions) = match message {
+        VersionedMessage::Legacy(message) => (&message.account_keys, &mut message.instructions),
+        VersionedMessage::V0(message) => (&message.account_keys, &mut message.instructions),
+    };
+    for ix in instructions.iter_mut() {
+        let is_compute_budget = account_keys
+            .get(ix.program_id_index as usize)
+            .is_some_and(|program_id| program_id.eq(&compute_budget::id()));
+        if is_compute_budget && ix.data.first().eq(&data.first()) {
+            ix.data = data.clone();
+        }
+    }
+}
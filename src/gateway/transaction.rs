@@ -0,0 +1,248 @@
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_client_wasm::solana_sdk::{
+    address_lookup_table::{self, state::AddressLookupTable, AddressLookupTableAccount},
+    message::VersionedMessage,
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+
+use super::{
+    parse_logs, parse_transaction_error, Gateway, GatewayError, GatewayResult, CONFIG_TTL,
+};
+use crate::tx::{decode_instruction, DecodedInstruction, TxBuilder, MEMO_PROGRAM_ID};
+
+/// A confirmed transaction with its instructions decoded, including those invoked by
+/// other programs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionDetails {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub fee: u64,
+    pub compute_units_consumed: Option<u64>,
+    pub error: Option<GatewayError>,
+    pub logs: Vec<String>,
+    pub memo: Option<String>,
+    pub instructions: Vec<DecodedInstruction>,
+}
+
+impl Gateway {
+    /// Fetches a lookup table. Tables only change when they are extended, so they are
+    /// cached as long as the config.
+    pub async fn get_lookup_table(
+        &self,
+        address: Pubkey,
+    ) -> GatewayResult<AddressLookupTableAccount> {
+        let data = self
+            .get_program_account_data(address, address_lookup_table::program::id(), CONFIG_TTL)
+            .await?;
+        let table = AddressLookupTable::deserialize(&data)
+            .or(Err(GatewayError::FailedDeserialization(address)))?;
+        Ok(AddressLookupTableAccount {
+            key: address,
+            addresses: table.addresses.to_vec(),
+        })
+    }
+
+    /// Builds a transaction ready to be signed. Accounts are loaded from the lookup table
+    /// if one is given, the compute unit limit is sized by simulating the transaction, and
+    /// the latest blockhash is set.
+    pub async fn build_transaction(
+        &self,
+        builder: TxBuilder,
+        lookup_table: Option<Pubkey>,
+    ) -> GatewayResult<VersionedTransaction> {
+        let mut lookup_tables = vec![];
+        if let Some(address) = lookup_table {
+            // A missing table shouldn't block the transaction, it just makes it bigger
+            match self.get_lookup_table(address).await {
+                Ok(table) => lookup_tables.push(table),
+                Err(err) => log::error!("Failed to fetch lookup table {}: {}", address, err),
+            }
+        }
+        let mut tx = builder.lookup_tables(lookup_tables).build()?;
+        self.simulate_compute_units(&mut tx).await?;
+        tx.message
+            .set_recent_blockhash(self.get_latest_blockhash().await?);
+        Ok(tx)
+    }
+
+    /// Sends a signed transaction. Fails with the simulation result if the transaction
+    /// fails preflight.
+    pub async fn send_transaction(&self, tx: &VersionedTransaction) -> GatewayResult<Signature> {
+        let params = send_transaction_params(
+            tx,
+            json!({
+                "encoding": "base64",
+                "preflightCommitment": "confirmed",
+            }),
+        )?;
+        let res = self
+            .rpc_request("sendTransaction", params)
+            .await
+            .map_err(|err| err.with_message(&tx.message))?;
+        res.as_str()
+            .and_then(|sig| sig.parse().ok())
+            .ok_or(GatewayError::Unknown("Invalid signature".to_string()))
+    }
+
+    // Sends a transaction again while it is waiting to confirm. It already passed
+    // preflight, and the rpc shouldn't queue retries of its own alongside ours.
+    pub(super) async fn try_rebroadcast_transaction(
+        &self,
+        tx: &VersionedTransaction,
+    ) -> GatewayResult<()> {
+        let params = send_transaction_params(
+            tx,
+            json!({
+                "encoding": "base64",
+                "skipPreflight": true,
+                "maxRetries": 0,
+            }),
+        )?;
+        self.try_rpc_request("sendTransaction", &params).await?;
+        Ok(())
+    }
+
+    /// Fetches a confirmed transaction and decodes its instructions.
+    pub async fn get_transaction_details(
+        &self,
+        sig: Signature,
+    ) -> GatewayResult<TransactionDetails> {
+        let res = self
+            .rpc_request(
+                "getTransaction",
+                json!([sig.to_string(), {
+                    "encoding": "base64",
+                    "commitment": "confirmed",
+                    "maxSupportedTransactionVersion": 0,
+                }]),
+            )
+            .await?;
+        if res.is_null() {
+            return Err(GatewayError::Unknown("Transaction not found".to_string()));
+        }
+        let tx = res["transaction"][0]
+            .as_str()
+            .and_then(|tx| base64::engine::general_purpose::STANDARD.decode(tx).ok())
+            .and_then(|buffer| bincode::deserialize::<VersionedTransaction>(&buffer).ok())
+            .ok_or(GatewayError::Unknown(
+                "Failed to decode transaction".to_string(),
+            ))?;
+        let meta = &res["meta"];
+
+        // Accounts loaded from lookup tables follow the static keys, writable first
+        let mut account_keys = tx.message.static_account_keys().to_vec();
+        for key in ["writable", "readonly"] {
+            account_keys.extend(
+                meta["loadedAddresses"][key]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|address| address.as_str()?.parse::<Pubkey>().ok()),
+            );
+        }
+        let key = |i: usize| account_keys.get(i).copied().unwrap_or_default();
+
+        let inner_instructions = meta["innerInstructions"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        let mut instructions = vec![];
+        let mut memo = None;
+        for (i, ix) in tx.message.instructions().iter().enumerate() {
+            let program_id = key(ix.program_id_index as usize);
+            if program_id.eq(&MEMO_PROGRAM_ID) {
+                memo = String::from_utf8(ix.data.clone()).ok();
+            }
+            instructions.push(decode_instruction(
+                program_id,
+                ix.accounts.iter().map(|i| key(*i as usize)).collect(),
+                &ix.data,
+                false,
+            ));
+            let inner = inner_instructions
+                .iter()
+                .find(|inner| inner["index"].as_u64().eq(&Some(i as u64)));
+            for ix in inner
+                .and_then(|inner| inner["instructions"].as_array())
+                .into_iter()
+                .flatten()
+            {
+                let data = ix["data"]
+                    .as_str()
+                    .and_then(|data| bs58::decode(data).into_vec().ok())
+                    .unwrap_or_default();
+                let program_id = key(ix["programIdIndex"].as_u64().unwrap_or_default() as usize);
+                let accounts = ix["accounts"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|i| i.as_u64())
+                    .map(|i| key(i as usize))
+                    .collect();
+                instructions.push(decode_instruction(program_id, accounts, &data, true));
+            }
+        }
+
+        Ok(TransactionDetails {
+            slot: res["slot"].as_u64().unwrap_or_default(),
+            block_time: res["blockTime"].as_i64(),
+            fee: meta["fee"].as_u64().unwrap_or_default(),
+            compute_units_consumed: meta["computeUnitsConsumed"].as_u64(),
+            error: (!meta["err"].is_null())
+                .then(|| parse_transaction_error(&meta["err"]).with_message(&tx.message)),
+            logs: parse_logs(&meta["logMessages"]),
+            memo,
+            instructions,
+        })
+    }
+
+    /// Decodes the instructions of a transaction which hasn't been sent, so it can be
+    /// reviewed before it is signed.
+    pub async fn decode_transaction(&self, tx: &VersionedTransaction) -> Vec<DecodedInstruction> {
+        let account_keys = self.account_keys(&tx.message).await;
+        let key = |i: u8| account_keys.get(i as usize).copied().unwrap_or_default();
+        tx.message
+            .instructions()
+            .iter()
+            .map(|ix| {
+                decode_instruction(
+                    key(ix.program_id_index),
+                    ix.accounts.iter().map(|i| key(*i)).collect(),
+                    &ix.data,
+                    false,
+                )
+            })
+            .collect()
+    }
+
+    /// Every account a message references, including those loaded from lookup tables.
+    pub async fn account_keys(&self, message: &VersionedMessage) -> Vec<Pubkey> {
+        let mut account_keys = message.static_account_keys().to_vec();
+        for lookup in message.address_table_lookups().unwrap_or_default() {
+            if let Ok(table) = self.get_lookup_table(lookup.account_key).await {
+                account_keys.extend(
+                    lookup
+                        .writable_indexes
+                        .iter()
+                        .chain(lookup.readonly_indexes.iter())
+                        .filter_map(|i| table.addresses.get(*i as usize)),
+                );
+            }
+        }
+        account_keys
+    }
+}
+
+// Encodes a transaction as the params of a sendTransaction request
+fn send_transaction_params(tx: &VersionedTransaction, config: Value) -> GatewayResult<Value> {
+    let bytes = bincode::serialize(tx).or(Err(GatewayError::Unknown(
+        "Failed to serialize transaction".to_string(),
+    )))?;
+    Ok(json!([
+        base64::engine::general_purpose::STANDARD.encode(bytes),
+        config
+    ]))
+}
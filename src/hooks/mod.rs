@@ -1,29 +1,49 @@
+mod use_account_subscription;
+mod use_address_book;
 mod use_appearance;
+mod use_cluster;
 mod use_date;
+mod use_endpoints;
 mod use_escrow;
 mod use_explorer;
 mod use_gateway;
+mod use_lookup_table;
 mod use_miner;
 mod use_miner_toolbar_state;
+mod use_mining_stats;
 mod use_ore_balance;
 mod use_ore_supply;
+mod use_pending_transactions;
 mod use_persistent;
 mod use_power_level;
+mod use_priority_fee;
 mod use_proof;
+mod use_slot;
 mod use_sol_balance;
+mod use_target_difficulty;
 mod use_transfers;
 pub mod use_wallet_adapter;
 
+pub use use_account_subscription::*;
+pub use use_address_book::*;
 pub use use_appearance::*;
+pub use use_cluster::*;
 pub use use_date::*;
+pub use use_endpoints::*;
 pub use use_escrow::*;
 pub use use_explorer::*;
 pub use use_gateway::*;
+pub use use_lookup_table::*;
 pub use use_miner::*;
 pub use use_miner_toolbar_state::*;
+pub use use_mining_stats::*;
 pub use use_ore_balance::*;
 pub use use_ore_supply::*;
+pub use use_pending_transactions::*;
 pub use use_power_level::*;
+pub use use_priority_fee::*;
 pub use use_proof::*;
+pub use use_slot::*;
 pub use use_sol_balance::*;
+pub use use_target_difficulty::*;
 pub use use_transfers::*;
@@ -0,0 +1,38 @@
+use dioxus::prelude::*;
+use futures::StreamExt;
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use web_time::Duration;
+
+use super::use_gateway;
+
+// How often to refresh the resource if the rpc doesn't support subscriptions
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Refetches a resource whenever the account changes on chain. The subscription is
+/// cancelled when the address or the gateway changes.
+pub fn use_account_subscription<T: 'static>(
+    mut resource: Resource<T>,
+    address: impl Fn() -> Option<Pubkey> + 'static,
+) {
+    let _ = use_resource(move || {
+        let address = address();
+        async move {
+            let Some(address) = address else {
+                return;
+            };
+            let gateway = use_gateway();
+            let mut updates = gateway.account_subscribe(address);
+            while updates.next().await.is_some() {
+                gateway.invalidate(&[address]);
+                resource.restart();
+            }
+
+            // The subscription is unavailable, so fall back to polling
+            loop {
+                async_std::task::sleep(POLL_INTERVAL).await;
+                gateway.invalidate(&[address]);
+                resource.restart();
+            }
+        }
+    });
+}
@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+use ore_api::consts::{BUS_ADDRESSES, TREASURY_ADDRESS};
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+use crate::hooks::use_persistent::use_persistent;
+
+const KEY: &str = "address_book";
+
+/// Max length of a label, in characters.
+pub const MAX_LABEL_LENGTH: usize = 32;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Contact {
+    /// The base58 address.
+    pub address: String,
+    pub label: String,
+}
+
+/// Labels the user has given to addresses. Exported and imported as a json array of
+/// contacts.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AddressBook(pub Vec<Contact>);
+
+impl AddressBook {
+    pub fn label(&self, address: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|contact| contact.address.eq(address))
+            .map(|contact| contact.label.as_str())
+    }
+
+    /// Adds a contact, or relabels it if the address is already in the book.
+    pub fn insert(&mut self, address: &str, label: &str) -> Result<(), String> {
+        let contact = validate_contact(address, label)?;
+        match self.0.iter_mut().find(|c| c.address.eq(&contact.address)) {
+            Some(c) => c.label = contact.label,
+            None => self.0.push(contact),
+        }
+        self.0.sort_by_key(|contact| contact.label.to_lowercase());
+        Ok(())
+    }
+
+    pub fn remove(&mut self, address: &str) {
+        self.0.retain(|contact| contact.address.ne(address));
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Merges contacts from an exported address book, relabeling addresses already in the
+    /// book. Nothing is imported if any contact is invalid. Returns the number of contacts
+    /// imported.
+    pub fn import(&mut self, json: &str) -> Result<usize, String> {
+        let contacts: Vec<Contact> =
+            serde_json::from_str(json).map_err(|_| "Invalid address book".to_string())?;
+        let mut book = self.clone();
+        for contact in &contacts {
+            book.insert(&contact.address, &contact.label)?;
+        }
+        *self = book;
+        Ok(contacts.len())
+    }
+}
+
+fn validate_contact(address: &str, label: &str) -> Result<Contact, String> {
+    let address =
+        Pubkey::from_str(address.trim()).map_err(|_| format!("Invalid address {}", address))?;
+    let label = label.trim();
+    if label.is_empty() {
+        return Err("Label is empty".to_string());
+    }
+    if label.chars().count().gt(&MAX_LABEL_LENGTH) {
+        return Err(format!(
+            "Label too long ({}/{} characters)",
+            label.chars().count(),
+            MAX_LABEL_LENGTH
+        ));
+    }
+    Ok(Contact {
+        address: address.to_string(),
+        label: label.to_string(),
+    })
+}
+
+/// The name to show for an address: the name of a program account, or the address's label
+/// in the address book.
+pub fn address_name(address_book: &AddressBook, address: &str) -> Option<String> {
+    if let Ok(pubkey) = Pubkey::from_str(address) {
+        if pubkey.eq(&TREASURY_ADDRESS) {
+            return Some("Treasury".to_string());
+        }
+        if let Some(index) = BUS_ADDRESSES.iter().position(|bus| bus.eq(&pubkey)) {
+            return Some(format!("Bus {}", index));
+        }
+    }
+    address_book.label(address).map(String::from)
+}
+
+pub fn use_address_book() -> Signal<AddressBook> {
+    use_context::<Signal<AddressBook>>()
+}
+
+// Persisted by the provider rather than by each use, since every activity row reads it
+pub fn use_address_book_provider() {
+    let mut address_book_persistent = use_persistent(KEY, AddressBook::default);
+    let address_book = use_context_provider(|| Signal::new(address_book_persistent.get()));
+    use_effect(move || address_book_persistent.set(address_book.read().clone()));
+}
@@ -0,0 +1,76 @@
+use std::{fmt, io, str::FromStr};
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gateway::{DEFAULT_API_URL, DEFAULT_RPC_URL, DEVNET_RPC_URL, LOCALNET_RPC_URL},
+    hooks::use_persistent::use_persistent,
+};
+
+const KEY: &str = "cluster";
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum Cluster {
+    #[default]
+    Mainnet,
+    Devnet,
+    Localnet,
+}
+
+impl Cluster {
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            Cluster::Mainnet => DEFAULT_RPC_URL,
+            Cluster::Devnet => DEVNET_RPC_URL,
+            Cluster::Localnet => LOCALNET_RPC_URL,
+        }
+    }
+
+    /// The transfers indexer only runs against mainnet, so other clusters
+    /// default to no api at all.
+    pub fn default_api_url(&self) -> &'static str {
+        match self {
+            Cluster::Mainnet => DEFAULT_API_URL,
+            Cluster::Devnet | Cluster::Localnet => "",
+        }
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cluster::Mainnet => write!(f, "Mainnet"),
+            Cluster::Devnet => write!(f, "Devnet"),
+            Cluster::Localnet => write!(f, "Localnet"),
+        }
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Mainnet" => Ok(Cluster::Mainnet),
+            "Devnet" => Ok(Cluster::Devnet),
+            "Localnet" => Ok(Cluster::Localnet),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown cluster",
+            )),
+        }
+    }
+}
+
+pub fn use_cluster() -> Signal<Cluster> {
+    let cluster = use_context::<Signal<Cluster>>();
+    let mut cluster_persistent = use_persistent(KEY, || Cluster::Mainnet);
+    use_effect(move || cluster_persistent.set(*cluster.read()));
+    cluster
+}
+
+pub fn use_cluster_provider() {
+    let cluster = use_persistent(KEY, || Cluster::Mainnet).get();
+    use_context_provider(|| Signal::new(cluster));
+}
@@ -0,0 +1,72 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::hooks::{use_persistent::use_persistent, Cluster};
+
+const KEY: &str = "endpoints";
+
+/// Network endpoints the gateway sends requests to. Rpc endpoints are listed in order
+/// of preference; later endpoints are used as fallbacks.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(from = "PersistedEndpoints")]
+pub struct Endpoints {
+    pub rpc_urls: Vec<String>,
+    pub api_url: String,
+}
+
+// Endpoints used to be saved with a single rpc url
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PersistedEndpoints {
+    Pool {
+        rpc_urls: Vec<String>,
+        api_url: String,
+    },
+    Single {
+        rpc_url: String,
+        api_url: String,
+    },
+}
+
+impl From<PersistedEndpoints> for Endpoints {
+    fn from(endpoints: PersistedEndpoints) -> Self {
+        let (rpc_urls, api_url) = match endpoints {
+            PersistedEndpoints::Pool { rpc_urls, api_url } => (rpc_urls, api_url),
+            PersistedEndpoints::Single { rpc_url, api_url } => (vec![rpc_url], api_url),
+        };
+        // The gateway can't send requests without an rpc
+        let rpc_urls = if rpc_urls.is_empty() {
+            Endpoints::default().rpc_urls
+        } else {
+            rpc_urls
+        };
+        Endpoints { rpc_urls, api_url }
+    }
+}
+
+impl Endpoints {
+    pub fn for_cluster(cluster: Cluster) -> Self {
+        Endpoints {
+            rpc_urls: vec![cluster.default_rpc_url().to_string()],
+            api_url: cluster.default_api_url().to_string(),
+        }
+    }
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Endpoints::for_cluster(Cluster::Mainnet)
+    }
+}
+
+pub fn use_endpoints() -> Signal<Endpoints> {
+    let endpoints = use_context::<Signal<Endpoints>>();
+    let mut endpoints_persistent = use_persistent(KEY, Endpoints::default);
+    use_effect(move || endpoints_persistent.set(endpoints.read().clone()));
+    endpoints
+}
+
+pub fn use_endpoints_provider() {
+    let endpoints = use_persistent(KEY, Endpoints::default).get();
+    use_context_provider(|| Signal::new(endpoints));
+}
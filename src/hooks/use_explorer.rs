@@ -2,8 +2,9 @@ use std::{fmt, io, str::FromStr};
 
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
 
-use crate::hooks::use_persistent::use_persistent;
+use crate::hooks::{use_cluster, use_endpoints, use_persistent::use_persistent, Cluster};
 
 const KEY: &str = "explorer";
 
@@ -58,22 +59,53 @@ pub fn use_explorer_provider() {
 
 pub fn use_explorer_account_url(address: String) -> String {
     let explorer = use_explorer();
+    let query = use_explorer_cluster_query();
     let e = *explorer.read();
     match e {
-        Explorer::Solana => format!("https://explorer.solana.com/address/{}", address),
-        Explorer::SolanaFm => format!("https://solana.fm/address/{}", address),
-        Explorer::Solscan => format!("https://solscan.io/account/{}", address),
-        Explorer::Xray => format!("https://xray.helius.xyz/account/{}", address),
+        Explorer::Solana => format!("https://explorer.solana.com/address/{}{}", address, query),
+        Explorer::SolanaFm => format!("https://solana.fm/address/{}{}", address, query),
+        Explorer::Solscan => format!("https://solscan.io/account/{}{}", address, query),
+        Explorer::Xray => format!("https://xray.helius.xyz/account/{}{}", address, query),
     }
 }
 
 pub fn use_explorer_transaction_url(signature: String) -> String {
     let explorer = use_explorer();
+    let query = use_explorer_cluster_query();
     let e = *explorer.read();
     match e {
-        Explorer::Solana => format!("https://explorer.solana.com/tx/{}", signature),
-        Explorer::SolanaFm => format!("https://solana.fm/tx/{}", signature),
-        Explorer::Solscan => format!("https://solscan.io/tx/{}", signature),
-        Explorer::Xray => format!("https://xray.helius.xyz/tx/{}", signature),
+        Explorer::Solana => format!("https://explorer.solana.com/tx/{}{}", signature, query),
+        Explorer::SolanaFm => format!("https://solana.fm/tx/{}{}", signature, query),
+        Explorer::Solscan => format!("https://solscan.io/tx/{}{}", signature, query),
+        Explorer::Xray => format!("https://xray.helius.xyz/tx/{}{}", signature, query),
+    }
+}
+
+// Each explorer has its own way of selecting a cluster. Mainnet is always the default.
+fn use_explorer_cluster_query() -> String {
+    let explorer = use_explorer();
+    let cluster = use_cluster();
+    let endpoints = use_endpoints();
+    let e = *explorer.read();
+    let c = *cluster.read();
+    let custom_url = || {
+        let rpc_url = endpoints
+            .read()
+            .rpc_urls
+            .first()
+            .cloned()
+            .unwrap_or_else(|| c.default_rpc_url().to_string());
+        form_urlencoded::byte_serialize(rpc_url.as_bytes()).collect::<String>()
+    };
+    match (e, c) {
+        (_, Cluster::Mainnet) => "".to_string(),
+        (Explorer::Solana | Explorer::Solscan, Cluster::Devnet) => "?cluster=devnet".to_string(),
+        (Explorer::Solana | Explorer::Solscan, Cluster::Localnet) => {
+            format!("?cluster=custom&customUrl={}", custom_url())
+        }
+        (Explorer::SolanaFm, Cluster::Devnet) => "?cluster=devnet-solana".to_string(),
+        (Explorer::SolanaFm, Cluster::Localnet) => "?cluster=localnet-solana".to_string(),
+        (Explorer::Xray, Cluster::Devnet) => "?network=devnet".to_string(),
+        (Explorer::Xray, Cluster::Localnet) => "?network=localnet".to_string(),
     }
 }
@@ -1,7 +1,37 @@
 use std::rc::Rc;
 
-use crate::gateway::{Gateway, API_URL, RPC_URL};
+use dioxus::prelude::*;
+
+use crate::gateway::Gateway;
+
+use super::use_endpoints;
 
 pub fn use_gateway() -> Rc<Gateway> {
-    Rc::new(Gateway::new(API_URL.to_string(), RPC_URL.to_string()))
+    consume_context::<Signal<Rc<Gateway>>>().cloned()
+}
+
+pub fn use_gateway_provider() {
+    let endpoints = use_endpoints();
+    let mut gateway = use_context_provider(|| {
+        let endpoints = endpoints.peek();
+        Signal::new(Rc::new(Gateway::new(
+            endpoints.api_url.clone(),
+            endpoints.rpc_urls.clone(),
+        )))
+    });
+
+    // Rebuild the gateway whenever the endpoints change
+    use_effect(move || {
+        let endpoints = endpoints.read();
+        let changed = {
+            let gateway = gateway.peek();
+            gateway.rpc_urls().ne(&endpoints.rpc_urls) || gateway.api_url().ne(&endpoints.api_url)
+        };
+        if changed {
+            gateway.set(Rc::new(Gateway::new(
+                endpoints.api_url.clone(),
+                endpoints.rpc_urls.clone(),
+            )));
+        }
+    });
 }
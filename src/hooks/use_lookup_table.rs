@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+
+use crate::hooks::{
+    use_cluster,
+    use_persistent::use_persistent,
+    use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
+    Cluster,
+};
+
+const KEY: &str = "lookup_tables";
+
+/// The address lookup table transactions load ORE accounts from, if the user has one.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct LookupTable(pub Option<Pubkey>);
+
+// Lookup tables belong to an authority on a cluster, so they are saved per cluster and
+// authority
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+struct LookupTables(HashMap<String, Pubkey>);
+
+fn lookup_table_key(cluster: Cluster, wallet_adapter: &WalletAdapter) -> Option<String> {
+    match wallet_adapter {
+        WalletAdapter::Connected(authority) => Some(format!("{}:{}", cluster, authority)),
+        WalletAdapter::Disconnected => None,
+    }
+}
+
+pub fn use_lookup_table() -> Signal<LookupTable> {
+    use_context::<Signal<LookupTable>>()
+}
+
+pub fn use_lookup_table_provider() {
+    let cluster = use_cluster();
+    let wallet_adapter = use_wallet_adapter();
+    let mut lookup_tables = use_persistent(KEY, LookupTables::default);
+    let mut lookup_table = use_context_provider(|| {
+        let table = lookup_table_key(*cluster.peek(), &wallet_adapter.peek())
+            .and_then(|key| lookup_tables.get().0.get(&key).copied());
+        Signal::new(LookupTable(table))
+    });
+
+    // Switch tables when the wallet or cluster changes
+    use_effect(move || {
+        let table = lookup_table_key(*cluster.read(), &wallet_adapter.read())
+            .and_then(|key| lookup_tables.get().0.get(&key).copied());
+        if lookup_table.peek().0.ne(&table) {
+            lookup_table.set(LookupTable(table));
+        }
+    });
+
+    // Save the table of the connected wallet
+    use_effect(move || {
+        let table = *lookup_table.read();
+        let Some(key) = lookup_table_key(*cluster.peek(), &wallet_adapter.peek()) else {
+            return;
+        };
+        let mut tables = lookup_tables.get();
+        let changed = match table.0 {
+            Some(address) => tables.0.insert(key, address).ne(&Some(address)),
+            None => tables.0.remove(&key).is_some(),
+        };
+        if changed {
+            lookup_tables.set(tables);
+        }
+    });
+}
@@ -2,41 +2,106 @@ use dioxus::prelude::*;
 use dioxus_sdk::utils::channel::use_channel;
 
 use super::{
-    use_miner_toolbar_state, use_power_level, use_proof, use_wallet_adapter::use_wallet_adapter,
+    use_lookup_table, use_miner_toolbar_state, use_mining_stats, use_power_level, use_priority_fee,
+    use_proof, use_target_difficulty,
+    use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
+    MinerStatus, ReadMinerToolbarState, UpdateMinerToolbarState,
 };
 use crate::miner::{Miner, WebWorkerResponse, WEB_WORKERS};
 
 pub fn use_miner() -> Signal<Miner> {
     let mut cx = use_channel::<WebWorkerResponse>(*WEB_WORKERS);
     let mut toolbar_state = use_miner_toolbar_state();
+    let mut mining_stats = use_mining_stats();
     let wallet_adapter = use_wallet_adapter();
     let power_level = use_power_level();
-    let miner = use_signal(|| Miner::new(cx.clone(), power_level));
+    let priority_fee = use_priority_fee();
+    let lookup_table = use_lookup_table();
+    let target_difficulty = use_target_difficulty();
+    let miner = use_signal(|| {
+        Miner::new(
+            cx.clone(),
+            power_level,
+            priority_fee,
+            lookup_table,
+            target_difficulty,
+        )
+    });
     let mut proof = use_proof();
 
+    // Stop the workers whenever mining stops
+    let status = use_memo(move || toolbar_state.status());
+    use_effect(move || {
+        if let MinerStatus::NotStarted | MinerStatus::Error = *status.read() {
+            miner.read().cancel();
+        }
+    });
+
+    // Stop mining when the wallet disconnects
+    use_effect(move || {
+        if let WalletAdapter::Disconnected = *wallet_adapter.read() {
+            if toolbar_state.peek().status.ne(&MinerStatus::NotStarted) {
+                toolbar_state.pause();
+            }
+        }
+    });
+
+    // Restart mining with the new number of workers when the power level changes
+    let mut last_power_level = *power_level.peek();
+    use_effect(move || {
+        let next = *power_level.read();
+        if next.eq(&last_power_level) {
+            return;
+        }
+        last_power_level = next;
+        if toolbar_state.peek().status.eq(&MinerStatus::Active) {
+            miner.read().cancel();
+            toolbar_state.start();
+        }
+    });
+
     // Process web worker results
     use_future(move || {
         let mut rx = cx.receiver();
         async move {
-            let mut power_level = usize::MAX;
-            let mut messages = vec![];
+            let mut messages: Vec<WebWorkerResponse> = vec![];
             while let Ok(msg) = rx.recv().await {
-                if msg.power_level.lt(&power_level) {
-                    power_level = msg.power_level;
+                // Every response counts towards the stats, even from cancelled batches
+                mining_stats.write().record(&msg);
+
+                // Skip responses from cancelled or already processed batches
+                if msg.batch.ne(&miner.read().batch()) {
+                    continue;
                 }
-                messages.push(msg);
-                if messages.len().gt(&power_level) {
-                    miner
-                        .read()
-                        .process_web_worker_results(
-                            &messages,
-                            &mut toolbar_state,
-                            &mut proof,
-                            wallet_adapter,
-                        )
-                        .await;
+                if messages
+                    .first()
+                    .map_or(false, |first| first.batch.ne(&msg.batch))
+                {
                     messages.clear();
                 }
+                let power_level = msg.power_level;
+
+                // Stop the other workers as soon as one reaches the target difficulty
+                let reached_target = msg.reached_target();
+                if reached_target {
+                    miner.read().cancel();
+                }
+                messages.push(msg);
+                if reached_target || messages.len().gt(&power_level) {
+                    // Submitting can wait for the spam cutoff, so it runs apart from this loop
+                    let messages = std::mem::take(&mut messages);
+                    spawn(async move {
+                        miner
+                            .read()
+                            .process_web_worker_results(
+                                &messages,
+                                &mut toolbar_state,
+                                &mut proof,
+                                wallet_adapter,
+                            )
+                            .await;
+                    });
+                }
             }
         }
     });
@@ -1,7 +1,9 @@
 use dioxus::prelude::*;
 use solana_sdk::blake3::Hash as Blake3Hash;
 
-#[derive(Copy, Clone, Debug)]
+use crate::gateway::GatewayError;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MinerStatus {
     NotStarted,
     Activating,
@@ -11,11 +13,11 @@ pub enum MinerStatus {
     Error,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum MinerStatusMessage {
     Searching,
     Submitting(u64),
-    Error,
+    Error(GatewayError),
     SignatureDenied,
 }
 
@@ -47,7 +49,7 @@ pub trait ReadMinerToolbarState {
 
 impl ReadMinerToolbarState for Signal<MinerToolbarState> {
     fn status_message(&self) -> MinerStatusMessage {
-        self.read().status_message
+        self.read().status_message.clone()
     }
 
     fn status(&self) -> MinerStatus {
@@ -73,7 +75,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
         let old = self.read();
         let new = MinerToolbarState {
             status: MinerStatus::Activating,
-            status_message: old.status_message,
+            status_message: old.status_message.clone(),
             display_hash: old.display_hash,
         };
         drop(old);
@@ -84,7 +86,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
         let old = self.read();
         let new = MinerToolbarState {
             status: MinerStatus::NotStarted,
-            status_message: old.status_message,
+            status_message: old.status_message.clone(),
             display_hash: old.display_hash,
         };
         drop(old);
@@ -95,7 +97,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
         let old = self.read();
         let new = MinerToolbarState {
             status: old.status,
-            status_message: old.status_message,
+            status_message: old.status_message.clone(),
             display_hash: hash,
         };
         drop(old);
@@ -117,7 +119,7 @@ impl UpdateMinerToolbarState for Signal<MinerToolbarState> {
         let old = self.read();
         let new = MinerToolbarState {
             status,
-            status_message: old.status_message,
+            status_message: old.status_message.clone(),
             display_hash: old.display_hash,
         };
         drop(old);
@@ -0,0 +1,11 @@
+use dioxus::prelude::*;
+
+use crate::miner::MiningStats;
+
+pub fn use_mining_stats() -> Signal<MiningStats> {
+    use_context::<Signal<MiningStats>>()
+}
+
+pub fn use_mining_stats_provider() {
+    use_context_provider(|| Signal::new(MiningStats::default()));
+}
@@ -2,73 +2,61 @@ use dioxus::prelude::*;
 use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 use solana_extra_wasm::account_decoder::parse_token::UiTokenAmount;
 
-use crate::gateway::{
-    ore_token_account_address, ore_token_account_address_v1, GatewayError, GatewayResult,
-};
+use crate::gateway::{ore_token_account_address, ore_token_account_address_v1, GatewayResult};
 
 use super::{
-    use_gateway,
+    use_account_subscription, use_gateway,
     use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
 };
 
 pub fn use_ore_balance() -> Resource<GatewayResult<UiTokenAmount>> {
-    let gateway = use_gateway();
     let wallet_adapter = use_wallet_adapter();
-    use_resource(move || {
-        let gateway = gateway.clone();
-        async move {
-            match *wallet_adapter.read() {
-                WalletAdapter::Connected(pubkey) => {
-                    let token_account_address = ore_token_account_address(pubkey);
-                    gateway
-                        .rpc
-                        .get_token_account_balance(&token_account_address)
-                        .await
-                        .map_err(GatewayError::from)
-                }
-                WalletAdapter::Disconnected => {
-                    Ok(UiTokenAmount::default(ore_api::consts::TOKEN_DECIMALS))
-                }
+    let balance = use_resource(move || async move {
+        match *wallet_adapter.read() {
+            WalletAdapter::Connected(pubkey) => {
+                let gateway = use_gateway();
+                let token_account_address = ore_token_account_address(pubkey);
+                gateway
+                    .get_token_balance(token_account_address, ore_api::consts::TOKEN_DECIMALS)
+                    .await
+            }
+            WalletAdapter::Disconnected => {
+                Ok(UiTokenAmount::default(ore_api::consts::TOKEN_DECIMALS))
             }
         }
-    })
+    });
+    use_account_subscription(balance, move || match *wallet_adapter.read() {
+        WalletAdapter::Disconnected => None,
+        WalletAdapter::Connected(pubkey) => Some(ore_token_account_address(pubkey)),
+    });
+    balance
 }
 
 pub fn use_ore_v1_balance() -> Resource<GatewayResult<UiTokenAmount>> {
-    let gateway = use_gateway();
     let wallet_adapter = use_wallet_adapter();
-    use_resource(move || {
-        let gateway = gateway.clone();
-        async move {
-            match *wallet_adapter.read() {
-                WalletAdapter::Connected(pubkey) => {
-                    let token_account_address = ore_token_account_address_v1(pubkey);
-                    gateway
-                        .rpc
-                        .get_token_account_balance(&token_account_address)
-                        .await
-                        .map_err(GatewayError::from)
-                }
-                WalletAdapter::Disconnected => {
-                    Ok(UiTokenAmount::default(ore_api::consts::TOKEN_DECIMALS_V1))
-                }
+    use_resource(move || async move {
+        match *wallet_adapter.read() {
+            WalletAdapter::Connected(pubkey) => {
+                let gateway = use_gateway();
+                let token_account_address = ore_token_account_address_v1(pubkey);
+                gateway
+                    .get_token_balance(token_account_address, ore_api::consts::TOKEN_DECIMALS_V1)
+                    .await
+            }
+            WalletAdapter::Disconnected => {
+                Ok(UiTokenAmount::default(ore_api::consts::TOKEN_DECIMALS_V1))
             }
         }
     })
 }
 
 pub fn use_ore_balance_user(pubkey: Pubkey) -> Resource<GatewayResult<UiTokenAmount>> {
-    let gateway = use_gateway();
     let token_account_address = ore_token_account_address(pubkey);
-    use_resource(move || {
-        let gateway = gateway.clone();
-        async move {
-            gateway
-                .rpc
-                .get_token_account_balance(&token_account_address)
-                .await
-                .map_err(GatewayError::from)
-        }
+    use_resource(move || async move {
+        let gateway = use_gateway();
+        gateway
+            .get_token_balance(token_account_address, ore_api::consts::TOKEN_DECIMALS)
+            .await
     })
 }
 
@@ -11,7 +11,7 @@ pub fn use_ore_supply() -> Resource<GatewayResult<UiTokenAmount>> {
         let gateway = gateway.clone();
         async move {
             gateway
-                .rpc
+                .rpc()?
                 .get_token_supply(&ore_api::consts::MINT_ADDRESS)
                 .await
                 .map_err(GatewayError::from)
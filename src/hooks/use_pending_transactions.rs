@@ -0,0 +1,223 @@
+use base64::Engine;
+use dioxus::prelude::*;
+use ore_api::consts::{TOKEN_DECIMALS, TOKEN_DECIMALS_V1};
+use serde::{Deserialize, Serialize};
+use solana_client_wasm::solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+
+use crate::{
+    gateway::{GatewayError, GatewayResult},
+    idb::{self, PENDING_TRANSACTIONS_STORE},
+};
+
+use super::use_gateway;
+
+// Max number of settled transactions to keep a record of
+const MAX_SETTLED: usize = 20;
+
+/// The flow a transaction was sent from.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TransactionKind {
+    Claim,
+    ClaimV1,
+    Send,
+    SendBatch,
+    Stake,
+    Upgrade,
+}
+
+impl TransactionKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            TransactionKind::Claim => "Claim",
+            TransactionKind::ClaimV1 => "Claim v1",
+            TransactionKind::Send => "Send",
+            TransactionKind::SendBatch => "Batch send",
+            TransactionKind::Stake => "Stake",
+            TransactionKind::Upgrade => "Upgrade",
+        }
+    }
+
+    /// Decimals of the token the amount is in.
+    pub fn decimals(&self) -> u8 {
+        match self {
+            TransactionKind::ClaimV1 | TransactionKind::Upgrade => TOKEN_DECIMALS_V1,
+            TransactionKind::Claim
+            | TransactionKind::Send
+            | TransactionKind::SendBatch
+            | TransactionKind::Stake => TOKEN_DECIMALS,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PendingTransactionStatus {
+    Pending,
+    Confirmed,
+    Failed(String),
+    Expired,
+}
+
+/// A signed transaction, recorded before it is sent so its outcome is known even if the
+/// app is closed before it confirms.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub signature: Signature,
+    pub kind: TransactionKind,
+    pub amount: Option<u64>,
+    pub timestamp: i64,
+    pub status: PendingTransactionStatus,
+    /// Whether the user has seen the outcome.
+    pub seen: bool,
+    // The signed transaction, base64 encoded, so it can be rebroadcast after a reload
+    tx: String,
+}
+
+impl PendingTransaction {
+    fn transaction(&self) -> Option<VersionedTransaction> {
+        base64::engine::general_purpose::STANDARD
+            .decode(&self.tx)
+            .ok()
+            .and_then(|buffer| bincode::deserialize(&buffer).ok())
+    }
+}
+
+pub fn use_pending_transactions() -> Signal<Vec<PendingTransaction>> {
+    consume_context::<Signal<Vec<PendingTransaction>>>()
+}
+
+/// Loads the transactions recorded in earlier sessions, and resumes confirming those
+/// which were still pending when the app closed.
+pub fn use_pending_transactions_provider() {
+    let mut pending_transactions = use_context_provider(|| Signal::new(vec![]));
+    use_future(move || async move {
+        let mut txs = match idb::get_all::<PendingTransaction>(PENDING_TRANSACTIONS_STORE).await {
+            Ok(txs) => txs,
+            Err(err) => {
+                log::error!("Failed to load pending transactions: {:?}", err);
+                return;
+            }
+        };
+        txs.sort_by_key(|tx| -tx.timestamp);
+        pending_transactions.set(txs.clone());
+        let gateway = use_gateway();
+        let resumed = txs
+            .into_iter()
+            .filter(|tx| tx.status.eq(&PendingTransactionStatus::Pending))
+            .map(|pending_tx| {
+                let gateway = gateway.clone();
+                async move {
+                    let res = match pending_tx.transaction() {
+                        Some(tx) => gateway.confirm_transaction(&tx).await,
+                        None => Err(GatewayError::Unknown(
+                            "Failed to decode transaction".to_string(),
+                        )),
+                    };
+                    settle_transaction(pending_transactions, pending_tx.signature, &res).await;
+                }
+            });
+        futures::future::join_all(resumed).await;
+    });
+}
+
+/// Records a signed transaction before it is sent.
+pub async fn track_transaction(
+    mut pending_transactions: Signal<Vec<PendingTransaction>>,
+    tx: &VersionedTransaction,
+    kind: TransactionKind,
+    amount: Option<u64>,
+) {
+    let Some(signature) = tx.signatures.first().copied() else {
+        return;
+    };
+    let Ok(bytes) = bincode::serialize(tx) else {
+        return;
+    };
+    let pending_tx = PendingTransaction {
+        signature,
+        kind,
+        amount,
+        timestamp: chrono::Utc::now().timestamp(),
+        status: PendingTransactionStatus::Pending,
+        seen: false,
+        tx: base64::engine::general_purpose::STANDARD.encode(bytes),
+    };
+    save(&pending_tx).await;
+    pending_transactions.write().insert(0, pending_tx);
+
+    // Forget the oldest settled transactions
+    let expired: Vec<Signature> = pending_transactions
+        .read()
+        .iter()
+        .filter(|tx| tx.status.ne(&PendingTransactionStatus::Pending))
+        .skip(MAX_SETTLED)
+        .map(|tx| tx.signature)
+        .collect();
+    forget_transactions(pending_transactions, &expired).await;
+}
+
+/// Records the outcome of a transaction. Does nothing if the transaction isn't tracked.
+pub async fn settle_transaction(
+    mut pending_transactions: Signal<Vec<PendingTransaction>>,
+    signature: Signature,
+    res: &GatewayResult<Signature>,
+) {
+    let status = match res {
+        Ok(_) => PendingTransactionStatus::Confirmed,
+        Err(GatewayError::TransactionExpired(_)) => PendingTransactionStatus::Expired,
+        Err(err) => PendingTransactionStatus::Failed(err.to_string()),
+    };
+    let pending_tx = {
+        let mut txs = pending_transactions.write();
+        let Some(pending_tx) = txs.iter_mut().find(|tx| tx.signature.eq(&signature)) else {
+            return;
+        };
+        if pending_tx.status.eq(&status) {
+            return;
+        }
+        pending_tx.status = status;
+        pending_tx.seen = false;
+        pending_tx.clone()
+    };
+    save(&pending_tx).await;
+}
+
+/// Marks every settled transaction as seen.
+pub async fn mark_transactions_seen(mut pending_transactions: Signal<Vec<PendingTransaction>>) {
+    let unseen: Vec<PendingTransaction> = pending_transactions
+        .write()
+        .iter_mut()
+        .filter(|tx| !tx.seen && tx.status.ne(&PendingTransactionStatus::Pending))
+        .map(|tx| {
+            tx.seen = true;
+            tx.clone()
+        })
+        .collect();
+    for pending_tx in unseen {
+        save(&pending_tx).await;
+    }
+}
+
+/// Deletes the records of the given transactions.
+pub async fn forget_transactions(
+    mut pending_transactions: Signal<Vec<PendingTransaction>>,
+    signatures: &[Signature],
+) {
+    if signatures.is_empty() {
+        return;
+    }
+    pending_transactions
+        .write()
+        .retain(|tx| !signatures.contains(&tx.signature));
+    for signature in signatures {
+        if let Err(err) = idb::delete(PENDING_TRANSACTIONS_STORE, &signature.to_string()).await {
+            log::error!("Failed to delete transaction {}: {:?}", signature, err);
+        }
+    }
+}
+
+async fn save(pending_tx: &PendingTransaction) {
+    let key = pending_tx.signature.to_string();
+    if let Err(err) = idb::put(PENDING_TRANSACTIONS_STORE, &key, pending_tx).await {
+        log::error!("Failed to save transaction {}: {:?}", key, err);
+    }
+}
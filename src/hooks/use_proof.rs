@@ -2,16 +2,16 @@ use dioxus::prelude::*;
 use ore_api::state::Proof;
 use solana_client_wasm::solana_sdk::pubkey::Pubkey;
 
-use crate::gateway::{escrow_pubkey, GatewayError, GatewayResult};
+use crate::gateway::{escrow_pubkey, proof_pubkey, GatewayError, GatewayResult};
 
 use super::{
-    use_escrow, use_gateway,
+    use_account_subscription, use_escrow, use_gateway,
     use_wallet_adapter::{use_wallet_adapter, WalletAdapter},
 };
 
 pub fn use_proof() -> Resource<GatewayResult<Proof>> {
     let wallet_adapter = use_wallet_adapter();
-    use_resource(move || async move {
+    let proof = use_resource(move || async move {
         match *wallet_adapter.read() {
             WalletAdapter::Disconnected => Err(GatewayError::AccountNotFound.into()),
             WalletAdapter::Connected(pubkey) => {
@@ -19,7 +19,12 @@ pub fn use_proof() -> Resource<GatewayResult<Proof>> {
                 gateway.get_proof(pubkey).await
             }
         }
-    })
+    });
+    use_account_subscription(proof, move || match *wallet_adapter.read() {
+        WalletAdapter::Disconnected => None,
+        WalletAdapter::Connected(pubkey) => Some(proof_pubkey(pubkey)),
+    });
+    proof
 }
 
 pub fn use_escrow_proof() -> Resource<GatewayResult<Proof>> {
@@ -40,10 +45,9 @@ pub fn use_escrow_proof() -> Resource<GatewayResult<Proof>> {
 }
 
 pub fn use_user_proof(authority: Pubkey) -> Resource<GatewayResult<Proof>> {
-    let gateway = use_gateway();
-    use_resource(move || {
-        let gateway = gateway.clone();
-        async move { gateway.get_proof(authority).await }
+    use_resource(move || async move {
+        let gateway = use_gateway();
+        gateway.get_proof(authority).await
     })
 }
 
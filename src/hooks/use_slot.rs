@@ -0,0 +1,17 @@
+use dioxus::prelude::*;
+use futures::StreamExt;
+
+use super::use_gateway;
+
+/// The latest slot processed by the rpc, streamed over its pubsub connection.
+pub fn use_slot() -> Signal<Option<u64>> {
+    let mut slot = use_signal(|| None);
+    let _ = use_resource(move || async move {
+        let gateway = use_gateway();
+        let mut updates = gateway.slot_subscribe();
+        while let Some(notification) = updates.next().await {
+            slot.set(notification["slot"].as_u64());
+        }
+    });
+    slot
+}
@@ -0,0 +1,141 @@
+use std::{fmt, io, str::FromStr};
+
+use dioxus::prelude::*;
+use ore_api::consts::BUS_EPOCH_REWARDS;
+use serde::{Deserialize, Serialize};
+
+use crate::hooks::use_persistent::use_persistent;
+
+const KEY: &str = "target_difficulty";
+
+/// When workers stop searching. Once any worker finds a hash at the target, all workers stop
+/// and the hash is submitted at the earliest time the program accepts it.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(from = "PersistedTargetDifficulty")]
+pub enum TargetDifficulty {
+    /// Workers search until the cutoff time.
+    #[default]
+    Off,
+    /// Targets the reward-optimal difficulty, from the program config.
+    Optimal,
+    Fixed(u32),
+}
+
+// Auto used to target the minimum difficulty, which a fixed target of 0 is raised to
+#[derive(Deserialize)]
+enum PersistedTargetDifficulty {
+    Off,
+    Auto,
+    Optimal,
+    Fixed(u32),
+}
+
+impl From<PersistedTargetDifficulty> for TargetDifficulty {
+    fn from(target_difficulty: PersistedTargetDifficulty) -> Self {
+        match target_difficulty {
+            PersistedTargetDifficulty::Off => TargetDifficulty::Off,
+            PersistedTargetDifficulty::Auto => TargetDifficulty::Fixed(0),
+            PersistedTargetDifficulty::Optimal => TargetDifficulty::Optimal,
+            PersistedTargetDifficulty::Fixed(difficulty) => TargetDifficulty::Fixed(difficulty),
+        }
+    }
+}
+
+impl TargetDifficulty {
+    /// The difficulty workers stop at, if any. Targets below the minimum are raised to it, so
+    /// an early hash always earns a reward.
+    pub fn target(&self, min_difficulty: u32, base_reward_rate: u64) -> Option<u32> {
+        match self {
+            TargetDifficulty::Off => None,
+            TargetDifficulty::Optimal => Some(optimal_difficulty(min_difficulty, base_reward_rate)),
+            TargetDifficulty::Fixed(difficulty) => Some((*difficulty).max(min_difficulty)),
+        }
+    }
+}
+
+/// The lowest difficulty whose reward reaches the most a bus pays out in an epoch. Harder
+/// hashes can't earn more.
+pub fn optimal_difficulty(min_difficulty: u32, base_reward_rate: u64) -> u32 {
+    let mut difficulty = min_difficulty;
+    let mut reward = base_reward_rate.max(1);
+    while reward.lt(&BUS_EPOCH_REWARDS) {
+        reward = reward.saturating_mul(2);
+        difficulty += 1;
+    }
+    difficulty
+}
+
+impl fmt::Display for TargetDifficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetDifficulty::Off => write!(f, "Off"),
+            TargetDifficulty::Optimal => write!(f, "Optimal"),
+            TargetDifficulty::Fixed(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl FromStr for TargetDifficulty {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Off" => Ok(TargetDifficulty::Off),
+            "Optimal" => Ok(TargetDifficulty::Optimal),
+            "Custom" => Ok(TargetDifficulty::Fixed(0)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Unknown target difficulty",
+            )),
+        }
+    }
+}
+
+pub fn use_target_difficulty() -> Signal<TargetDifficulty> {
+    let target_difficulty = use_context::<Signal<TargetDifficulty>>();
+    let mut target_difficulty_persistent = use_persistent(KEY, TargetDifficulty::default);
+    use_effect(move || target_difficulty_persistent.set(*target_difficulty.read()));
+    target_difficulty
+}
+
+pub fn use_target_difficulty_provider() {
+    let target_difficulty = use_persistent(KEY, TargetDifficulty::default).get();
+    use_context_provider(|| Signal::new(target_difficulty));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optimal_difficulty_reaches_the_bus_epoch_rewards() {
+        let rate = BUS_EPOCH_REWARDS.div_ceil(8);
+        assert_eq!(optimal_difficulty(10, rate), 13);
+        assert_eq!(optimal_difficulty(10, rate + 1), 13);
+        assert_eq!(optimal_difficulty(10, BUS_EPOCH_REWARDS), 10);
+        assert_eq!(optimal_difficulty(10, u64::MAX), 10);
+        assert!(optimal_difficulty(10, 0).gt(&10));
+    }
+
+    #[test]
+    fn fixed_targets_are_raised_to_the_minimum() {
+        assert_eq!(TargetDifficulty::Off.target(10, 1), None);
+        assert_eq!(TargetDifficulty::Fixed(4).target(10, 1), Some(10));
+        assert_eq!(TargetDifficulty::Fixed(14).target(10, 1), Some(14));
+        assert_eq!(
+            TargetDifficulty::Optimal.target(10, BUS_EPOCH_REWARDS),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn persisted_auto_keeps_targeting_the_minimum() {
+        let parse = |s: &str| serde_json::from_str::<TargetDifficulty>(s).unwrap();
+        assert_eq!(parse("\"Off\""), TargetDifficulty::Off);
+        assert_eq!(parse("\"Auto\""), TargetDifficulty::Fixed(0));
+        assert_eq!(parse("\"Optimal\""), TargetDifficulty::Optimal);
+        assert_eq!(parse("{\"Fixed\":12}"), TargetDifficulty::Fixed(12));
+        let optimal = serde_json::to_string(&TargetDifficulty::Optimal).unwrap();
+        assert_eq!(parse(&optimal), TargetDifficulty::Optimal);
+    }
+}
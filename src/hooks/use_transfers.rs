@@ -1,8 +1,10 @@
+use std::str::FromStr;
+
 use dioxus::prelude::*;
-use ore_types::{response::ListTransfersResponse, Transfer};
-use solana_client_wasm::solana_sdk::pubkey::Pubkey;
+use ore_types::Transfer;
+use solana_client_wasm::solana_sdk::{pubkey::Pubkey, signature::Signature};
 
-use crate::gateway::GatewayResult;
+use crate::gateway::{GatewayError, GatewayResult, HistoryPage, HistorySource, TransactionDetails};
 
 use super::{
     use_gateway,
@@ -18,46 +20,91 @@ pub enum ActivityFilter {
 }
 
 pub fn use_transfer(sig: String) -> Resource<GatewayResult<Transfer>> {
-    let gateway = use_gateway();
     use_resource(move || {
-        let gateway = gateway.clone();
         let sig = sig.clone();
-        async move { gateway.get_transfer(sig).await }
+        async move {
+            let gateway = use_gateway();
+            gateway.get_transfer(sig).await
+        }
     })
 }
 
-pub fn use_user_transfers(
-    user_id: Pubkey,
-    offset: Signal<u64>,
-) -> Resource<GatewayResult<ListTransfersResponse>> {
-    let gateway = use_gateway();
+pub fn use_transaction_details(sig: String) -> Resource<GatewayResult<TransactionDetails>> {
     use_resource(move || {
-        let gateway = gateway.clone();
+        let sig = sig.clone();
         async move {
-            let offset = *offset.read();
-            gateway
-                .list_transfers(Some(user_id), offset, ACTIVITY_TABLE_PAGE_LIMIT)
-                .await
+            let gateway = use_gateway();
+            let sig = Signature::from_str(&sig)
+                .or(Err(GatewayError::Unknown("Invalid signature".to_string())))?;
+            gateway.get_transaction_details(sig).await
         }
     })
 }
 
+pub fn use_user_transfers(
+    user_id: Pubkey,
+    offset: Signal<u64>,
+) -> Resource<GatewayResult<HistoryPage>> {
+    let mut source = use_signal::<Option<HistorySource>>(|| None);
+    use_resource(move || async move {
+        let offset = *offset.read();
+        let gateway = use_gateway();
+        let page = gateway
+            .list_history(
+                user_id,
+                offset,
+                ACTIVITY_TABLE_PAGE_LIMIT,
+                page_source(offset, source),
+            )
+            .await?;
+        source.set(Some(page.source));
+        Ok(page)
+    })
+}
+
 pub fn use_transfers(
     filter: Signal<ActivityFilter>,
     offset: Signal<u64>,
-) -> Resource<GatewayResult<ListTransfersResponse>> {
+) -> Resource<GatewayResult<HistoryPage>> {
     let wallet_adapter = use_wallet_adapter();
+    let mut source = use_signal::<Option<HistorySource>>(|| None);
     use_resource(move || async move {
         let offset = *offset.read();
-        let user = match *filter.read() {
-            ActivityFilter::Global => None,
-            ActivityFilter::Personal => match *wallet_adapter.read() {
-                WalletAdapter::Connected(pubkey) => Some(pubkey),
-                WalletAdapter::Disconnected => Some(Pubkey::new_from_array([0; 32])),
-            },
-        };
-        use_gateway()
-            .list_transfers(user, offset, ACTIVITY_TABLE_PAGE_LIMIT)
-            .await
+        let gateway = use_gateway();
+        match *filter.read() {
+            ActivityFilter::Global => gateway
+                .list_transfers(None, offset, ACTIVITY_TABLE_PAGE_LIMIT)
+                .await
+                .map(HistoryPage::from),
+            // Personal activity can be built from the rpc when the api is unavailable
+            ActivityFilter::Personal => {
+                let WalletAdapter::Connected(user) = *wallet_adapter.read() else {
+                    return Ok(HistoryPage {
+                        data: vec![],
+                        has_more: false,
+                        source: HistorySource::Api,
+                    });
+                };
+                let page = gateway
+                    .list_history(
+                        user,
+                        offset,
+                        ACTIVITY_TABLE_PAGE_LIMIT,
+                        page_source(offset, source),
+                    )
+                    .await?;
+                source.set(Some(page.source));
+                Ok(page)
+            }
+        }
     })
 }
+
+// The first page may come from either source, and later pages continue from the same one
+fn page_source(offset: u64, source: Signal<Option<HistorySource>>) -> Option<HistorySource> {
+    if offset.eq(&0) {
+        None
+    } else {
+        *source.peek()
+    }
+}
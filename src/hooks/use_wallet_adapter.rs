@@ -2,14 +2,19 @@ use base64::Engine;
 use dioxus::prelude::*;
 use ore_api::consts::{TOKEN_DECIMALS, TOKEN_DECIMALS_V1};
 use solana_client_wasm::solana_sdk::{
-    pubkey::Pubkey, signature::Signature, transaction::Transaction,
+    pubkey::Pubkey, signature::Signature, transaction::VersionedTransaction,
 };
 use solana_extra_wasm::account_decoder::parse_token::UiTokenAmount;
 
-use crate::gateway::{ore_token_account_address, ore_token_account_address_v1};
+use crate::gateway::{
+    ore_token_account_address, ore_token_account_address_v1, GatewayError, GatewayResult,
+    SignatureStatus,
+};
 use crate::hooks::UiTokenAmountDefault;
 
-use super::use_gateway;
+use super::{
+    settle_transaction, track_transaction, use_gateway, use_pending_transactions, TransactionKind,
+};
 
 pub fn use_wallet_adapter() -> Signal<WalletAdapter> {
     use_context::<Signal<WalletAdapter>>()
@@ -40,38 +45,29 @@ pub fn use_wallet_adapter_provider() {
 // so will have to pack many future here
 // in a big result if we want more reactive async data
 pub fn use_ore_balances() -> Resource<Option<Balances>> {
-    let gateway = use_gateway();
     let signal = use_wallet_adapter();
-    use_resource(move || {
-        let gateway = gateway.clone();
-        async move {
-            match *signal.read() {
-                WalletAdapter::Connected(pubkey) => {
-                    let token_account_address_v1 = ore_token_account_address_v1(pubkey);
-                    let token_account_address_v2 = ore_token_account_address(pubkey);
-                    let balance_v1 = gateway
-                        .rpc
-                        .get_token_account_balance(&token_account_address_v1)
-                        .await
-                        .unwrap_or(UiTokenAmount::default(TOKEN_DECIMALS_V1));
-                    let balance_v2 = gateway
-                        .rpc
-                        .get_token_account_balance(&token_account_address_v2)
-                        .await
-                        .unwrap_or(UiTokenAmount::default(TOKEN_DECIMALS));
-                    Some(Balances {
-                        v1: balance_v1,
-                        _v2: balance_v2,
-                    })
-                }
-                WalletAdapter::Disconnected => None,
+    use_resource(move || async move {
+        match *signal.read() {
+            WalletAdapter::Connected(pubkey) => {
+                let gateway = use_gateway();
+                let token_account_address_v1 = ore_token_account_address_v1(pubkey);
+                let token_account_address_v2 = ore_token_account_address(pubkey);
+                let (balance_v1, balance_v2) = futures::join!(
+                    gateway.get_token_balance(token_account_address_v1, TOKEN_DECIMALS_V1),
+                    gateway.get_token_balance(token_account_address_v2, TOKEN_DECIMALS),
+                );
+                Some(Balances {
+                    v1: balance_v1.unwrap_or(UiTokenAmount::default(TOKEN_DECIMALS_V1)),
+                    _v2: balance_v2.unwrap_or(UiTokenAmount::default(TOKEN_DECIMALS)),
+                })
             }
+            WalletAdapter::Disconnected => None,
         }
     })
 }
 
-pub fn invoke_signature(tx: Transaction, mut signal: Signal<InvokeSignatureStatus>) {
-    signal.set(InvokeSignatureStatus::Waiting);
+/// Asks the wallet to sign a transaction.
+pub async fn sign_transaction(tx: &VersionedTransaction) -> GatewayResult<VersionedTransaction> {
     let mut eval = eval(
         r#"
         let msg = await dioxus.recv();
@@ -79,76 +75,115 @@ pub fn invoke_signature(tx: Transaction, mut signal: Signal<InvokeSignatureStatu
         dioxus.send(signed);
         "#,
     );
-    match bincode::serialize(&tx) {
-        Ok(vec) => {
-            let b64 = base64::engine::general_purpose::STANDARD.encode(vec);
-            let res = eval.send(serde_json::Value::String(b64));
-            match res {
-                Ok(()) => {
-                    spawn(async move {
-                        let res = eval.recv().await;
-                        match res {
-                            Ok(serde_json::Value::String(string)) => {
-                                let gateway = use_gateway();
-                                let decode_res = base64::engine::general_purpose::STANDARD
-                                    .decode(string)
-                                    .ok()
-                                    .and_then(|buffer| bincode::deserialize(&buffer).ok());
-                                let rpc_res = match decode_res {
-                                    Some(tx) => {
-                                        log::info!("Sending: {:?}", tx);
-                                        let x = gateway.rpc.send_transaction(&tx).await;
-                                        log::info!("Sent: {:?}", x);
-                                        x.ok()
-                                    }
-                                    None => {
-                                        log::info!("error decoding tx");
-                                        None
-                                    }
-                                };
-                                log::info!("Dec: {:?}", rpc_res);
-                                match rpc_res {
-                                    Some(sig) => {
-                                        log::info!("sig: {}", sig);
-                                        let confirmed = gateway.confirm_signature(sig).await;
-                                        if confirmed.is_ok() {
-                                            signal.set(InvokeSignatureStatus::Done(sig));
-                                        } else {
-                                            signal.set(InvokeSignatureStatus::Timeout)
-                                        }
-                                    }
-                                    None => {
-                                        log::info!("error sending tx");
-                                        signal.set(InvokeSignatureStatus::DoneWithError)
-                                    }
-                                }
-                            }
-                            _ => {
-                                log::info!("err recv val");
-                                signal.set(InvokeSignatureStatus::DoneWithError)
-                            }
-                        };
-                    });
-                }
-                Err(_err) => {
-                    log::info!("err sending val");
-                    signal.set(InvokeSignatureStatus::DoneWithError)
+    let bytes = bincode::serialize(tx).map_err(|err| GatewayError::Unknown(err.to_string()))?;
+    let b64 = base64::engine::general_purpose::STANDARD.encode(bytes);
+    eval.send(serde_json::Value::String(b64))
+        .map_err(|err| GatewayError::Unknown(err.to_string()))?;
+    match eval.recv().await {
+        Ok(serde_json::Value::String(string)) => base64::engine::general_purpose::STANDARD
+            .decode(string)
+            .ok()
+            .and_then(|buffer| bincode::deserialize::<VersionedTransaction>(&buffer).ok())
+            .ok_or(GatewayError::Unknown(
+                "Failed to decode signed transaction".to_string(),
+            )),
+        _ => Err(GatewayError::SignatureDenied),
+    }
+}
+
+/// Signs and sends a transaction, and waits for it to confirm. The blockhash is refreshed
+/// first, so a transaction which expired can be signed again, unless others have already
+/// signed it. Transactions of a given kind are tracked until they settle, even across
+/// reloads.
+pub fn invoke_signature(
+    mut tx: VersionedTransaction,
+    mut signal: Signal<InvokeSignatureStatus>,
+    kind: Option<TransactionKind>,
+    amount: Option<u64>,
+) {
+    signal.set(InvokeSignatureStatus::Waiting);
+    let pending_transactions = use_pending_transactions();
+    spawn(async move {
+        let gateway = use_gateway();
+        // A new blockhash would invalidate the signatures of a partially signed transaction
+        let is_partially_signed = tx
+            .signatures
+            .iter()
+            .any(|sig| sig.ne(&Signature::default()));
+        if !is_partially_signed {
+            match gateway.get_latest_blockhash().await {
+                Ok(blockhash) => tx.message.set_recent_blockhash(blockhash),
+                Err(err) => {
+                    signal.set(InvokeSignatureStatus::DoneWithError(err));
+                    return;
                 }
             }
         }
-        Err(err) => {
-            log::info!("err serializing tx: {}", err);
-            signal.set(InvokeSignatureStatus::DoneWithError)
+        let tx = match sign_transaction(&tx).await {
+            Ok(tx) => tx,
+            Err(err) => {
+                log::info!("error signing tx: {}", err);
+                signal.set(InvokeSignatureStatus::DoneWithError(err));
+                return;
+            }
+        };
+        if let Some(kind) = kind {
+            track_transaction(pending_transactions, &tx, kind, amount).await;
+        }
+        let res = gateway.send_and_confirm(&tx).await;
+        settle_transaction(pending_transactions, tx.signatures[0], &res).await;
+        match res {
+            Ok(sig) => {
+                gateway.invalidate(&gateway.account_keys(&tx.message).await);
+                signal.set(InvokeSignatureStatus::Done(sig));
+            }
+            Err(GatewayError::TransactionExpired(sig)) => {
+                signal.set(InvokeSignatureStatus::Expired(sig));
+            }
+            Err(err) => {
+                log::info!("error sending tx: {}", err);
+                signal.set(InvokeSignatureStatus::DoneWithError(err));
+            }
         }
-    };
+    });
+}
+
+/// Checks again whether an expired transaction landed. Rpcs can be slow to report a
+/// transaction, so one reported as expired may turn out to have confirmed.
+pub fn recheck_signature(
+    tx: VersionedTransaction,
+    sig: Signature,
+    mut signal: Signal<InvokeSignatureStatus>,
+    mut checking: Signal<bool>,
+) {
+    checking.set(true);
+    let pending_transactions = use_pending_transactions();
+    spawn(async move {
+        let gateway = use_gateway();
+        match gateway.get_signature_status(sig).await {
+            Ok(SignatureStatus::Confirmed) => {
+                settle_transaction(pending_transactions, sig, &Ok(sig)).await;
+                gateway.invalidate(&gateway.account_keys(&tx.message).await);
+                signal.set(InvokeSignatureStatus::Done(sig));
+            }
+            Ok(SignatureStatus::Failed(err)) => {
+                let err = err.with_message(&tx.message);
+                settle_transaction(pending_transactions, sig, &Err(err.clone())).await;
+                signal.set(InvokeSignatureStatus::DoneWithError(err));
+            }
+            Ok(SignatureStatus::Pending) => {}
+            Err(err) => log::error!("Failed to check signature {}: {}", sig, err),
+        }
+        checking.set(false);
+    });
 }
 
 #[derive(PartialEq)]
 pub enum InvokeSignatureStatus {
     Start,
     Waiting,
-    DoneWithError,
-    Timeout,
+    DoneWithError(GatewayError),
+    Expired(Signature),
     Done(Signature),
 }
 
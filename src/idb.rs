@@ -0,0 +1,114 @@
+use std::cell::OnceCell;
+
+use futures::{
+    future::{LocalBoxFuture, Shared},
+    FutureExt,
+};
+use js_sys::{Array, Promise};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, IdbDatabase, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+const DB_NAME: &str = "ore";
+
+// Bump when adding a store, so it is created on the next open
+const DB_VERSION: u32 = 1;
+
+/// Object stores of the app's IndexedDB database.
+pub const STORES: [&str; 1] = [PENDING_TRANSACTIONS_STORE];
+
+pub const PENDING_TRANSACTIONS_STORE: &str = "pending_transactions";
+
+thread_local! {
+    // The database is opened once, on first use, and shared by every request after
+    static DB: OnceCell<Shared<LocalBoxFuture<'static, Result<IdbDatabase, JsValue>>>> =
+        const { OnceCell::new() };
+}
+
+/// Writes a value to a store, replacing any value with the same key. Values are stored as
+/// json, so they read back the same as from local storage.
+pub async fn put<T: Serialize>(store: &str, key: &str, value: &T) -> Result<(), JsValue> {
+    let json = serde_json::to_string(value).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    let store = object_store(store, IdbTransactionMode::Readwrite).await?;
+    request(&store.put_with_key(&JsValue::from_str(&json), &JsValue::from_str(key))?).await?;
+    Ok(())
+}
+
+pub async fn delete(store: &str, key: &str) -> Result<(), JsValue> {
+    let store = object_store(store, IdbTransactionMode::Readwrite).await?;
+    request(&store.delete(&JsValue::from_str(key))?).await?;
+    Ok(())
+}
+
+/// Reads every value in a store. Values which no longer deserialize are skipped.
+pub async fn get_all<T: DeserializeOwned>(store: &str) -> Result<Vec<T>, JsValue> {
+    let store = object_store(store, IdbTransactionMode::Readonly).await?;
+    let values: Array = request(&store.get_all()?).await?.dyn_into()?;
+    Ok(values
+        .iter()
+        .filter_map(|value| value.as_string())
+        .filter_map(|json| serde_json::from_str(&json).ok())
+        .collect())
+}
+
+// Opens a store in a new transaction. The transaction commits once its requests complete.
+async fn object_store(store: &str, mode: IdbTransactionMode) -> Result<IdbObjectStore, JsValue> {
+    database()
+        .await?
+        .transaction_with_str_and_mode(store, mode)?
+        .object_store(store)
+}
+
+// Returns the open database, opening it if this is the first request
+async fn database() -> Result<IdbDatabase, JsValue> {
+    DB.with(|db| db.get_or_init(|| open().boxed_local().shared()).clone())
+        .await
+}
+
+// Opens the database, creating any stores it is missing
+async fn open() -> Result<IdbDatabase, JsValue> {
+    let factory = window()
+        .ok_or(JsValue::from_str("No window"))?
+        .indexed_db()?
+        .ok_or(JsValue::from_str("IndexedDB unavailable"))?;
+    let req = factory.open_with_u32(DB_NAME, DB_VERSION)?;
+    let upgrade_req = req.clone();
+    let onupgradeneeded = Closure::once_into_js(move || {
+        if let Ok(db) = upgrade_req
+            .result()
+            .and_then(|db| db.dyn_into::<IdbDatabase>())
+        {
+            for store in STORES {
+                if !db.object_store_names().contains(store) {
+                    db.create_object_store(store).ok();
+                }
+            }
+        }
+    });
+    req.set_onupgradeneeded(Some(onupgradeneeded.unchecked_ref()));
+    request(&req).await?.dyn_into()
+}
+
+// Resolves with the result of a request once it succeeds
+async fn request(req: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let success_req = req.clone();
+        let onsuccess = Closure::once_into_js(move || {
+            resolve
+                .call1(&JsValue::NULL, &success_req.result().unwrap_or_default())
+                .ok();
+        });
+        let onerror = Closure::once_into_js(move || {
+            reject
+                .call1(
+                    &JsValue::NULL,
+                    &JsValue::from_str("IndexedDB request failed"),
+                )
+                .ok();
+        });
+        req.set_onsuccess(Some(onsuccess.unchecked_ref()));
+        req.set_onerror(Some(onerror.unchecked_ref()));
+    });
+    JsFuture::from(promise).await
+}
@@ -6,14 +6,19 @@ use tracing::Level;
 mod components;
 mod gateway;
 mod hooks;
+mod idb;
 mod metrics;
 mod miner;
 mod route;
+mod tx;
 mod utils;
 
 use crate::{
     hooks::{
-        use_appearance_provider, use_explorer_provider, use_power_level_provider,
+        use_address_book_provider, use_appearance_provider, use_cluster_provider,
+        use_endpoints_provider, use_explorer_provider, use_gateway_provider,
+        use_lookup_table_provider, use_pending_transactions_provider, use_power_level_provider,
+        use_priority_fee_provider, use_target_difficulty_provider,
         use_wallet_adapter::use_wallet_adapter_provider,
     },
     route::Route,
@@ -28,9 +33,17 @@ fn main() {
 pub fn App() -> Element {
     // Global variables
     use_appearance_provider();
+    use_cluster_provider();
+    use_endpoints_provider();
+    use_gateway_provider();
     use_explorer_provider();
+    use_address_book_provider();
     use_power_level_provider();
+    use_target_difficulty_provider();
+    use_priority_fee_provider();
+    use_pending_transactions_provider();
     use_wallet_adapter_provider();
+    use_lookup_table_provider();
 
     // Render
     rsx! {
@@ -0,0 +1,29 @@
+use dioxus_sdk::utils::channel::UseChannel;
+
+use super::{WebWorkerRequest, WebWorkerResponse};
+
+/// Runs the miner's hashing workers. The web build mines in web workers, and the desktop
+/// build in native threads.
+pub trait MinerBackend: Sized {
+    /// Number of workers the machine can run in parallel.
+    fn concurrency() -> usize;
+
+    /// Creates a worker per unit of concurrency. Workers send their results on the channel.
+    fn new(cx: UseChannel<WebWorkerResponse>) -> Self;
+
+    fn workers(&self) -> usize;
+
+    /// Starts a worker searching for a solution. The worker sends its best solution once the
+    /// request's cutoff time passes.
+    fn post(&self, worker: usize, req: WebWorkerRequest);
+
+    /// Stops every worker's search within an interval of hashes. Cancelled searches still
+    /// respond with the work they did.
+    fn cancel(&self);
+}
+
+#[cfg(feature = "desktop")]
+pub type Backend = super::ThreadPool;
+
+#[cfg(not(feature = "desktop"))]
+pub type Backend = super::WebWorkerPool;
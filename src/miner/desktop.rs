@@ -1,64 +1,81 @@
-//     let ch = self.ch.clone();
-//     let flag = Arc::new(AtomicBool::new(false));
-//     let result = Arc::new(Mutex::new(MiningResult::default()));
-//     let power_percent = ((self.power_level.read().0 + 1) as f64) / 8f64;
-//     let concurrency = num_cpus::get() as u64;
-//     let tuned_concurrency = ((concurrency as f64) * power_percent).round() as u64;
-//     let handles: Vec<_> = (0..tuned_concurrency)
-//         .map(|i| {
-//             std::thread::spawn({
-//                 let flag = flag.clone();
-//                 let result = result.clone();
-//                 move || {
-//                     let nonce =
-//                         u64::MAX.saturating_div(tuned_concurrency).saturating_mul(i);
-//                     if let Some(res) =
-//                         find_next_hash_par(hash, difficulty, signer, nonce, flag.clone())
-//                     {
-//                         flag.store(true, Ordering::Relaxed);
-//                         let mut w_result = result.lock().unwrap();
-//                         *w_result = res;
-//                     }
-//                 }
-//             })
-//         })
-//         .collect();
-//     async_std::task::spawn(async move {
-//         for h in handles {
-//             h.join().unwrap();
-//         }
-//         let res = {
-//             let r_result = result.lock().unwrap();
-//             r_result.clone()
-//         };
-//         ch.send(res).await.ok();
-//     });
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread,
+};
 
-fn find_next_hash_par(
-    hash: KeccakHash,
-    difficulty: KeccakHash,
-    signer: Pubkey,
-    nonce: u64,
-    flag: Arc<AtomicBool>,
-) -> Option<MiningResult> {
-    let mut next_hash: KeccakHash;
-    let mut nonce = nonce;
-    loop {
-        if nonce % 10_000 == 0 && flag.load(Ordering::Relaxed) {
-            return None;
+use dioxus::prelude::*;
+use dioxus_sdk::utils::channel::UseChannel;
+use futures::{channel::mpsc::unbounded, StreamExt};
+
+use super::{find_next_hash, MinerBackend, WebWorkerRequest, WebWorkerResponse};
+
+/// Mines in native threads, one per logical processor. Each thread waits for requests and
+/// hashes them with `drillx::hash_with_memory`, like a web worker.
+pub struct ThreadPool {
+    workers: Vec<Sender<(WebWorkerRequest, u64)>>,
+    // Bumped to cancel every request posted before it
+    generation: Arc<AtomicU64>,
+}
+
+impl MinerBackend for ThreadPool {
+    fn concurrency() -> usize {
+        thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1)
+    }
+
+    fn new(cx: UseChannel<WebWorkerResponse>) -> Self {
+        // Threads can't touch the channel's signals, so results are forwarded on the ui thread
+        let (results_tx, mut results_rx) = unbounded::<WebWorkerResponse>();
+        spawn(async move {
+            while let Some(res) = results_rx.next().await {
+                cx.send(res).await.ok();
+            }
+        });
+
+        let generation = Arc::new(AtomicU64::new(0));
+        let workers = (0..Self::concurrency())
+            .map(|i| {
+                let (tx, rx) = mpsc::channel::<(WebWorkerRequest, u64)>();
+                let results_tx = results_tx.clone();
+                let current = generation.clone();
+                thread::Builder::new()
+                    .name(format!("miner-{}", i))
+                    .spawn(move || {
+                        while let Ok((req, generation)) = rx.recv() {
+                            let is_cancelled = || current.load(Ordering::Relaxed).ne(&generation);
+                            let res = find_next_hash(&req, is_cancelled);
+                            if results_tx.unbounded_send(res).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .expect("failed to spawn miner thread");
+                tx
+            })
+            .collect();
+        ThreadPool {
+            workers,
+            generation,
         }
-        next_hash = hashv(&[
-            hash.as_ref(),
-            signer.as_ref(),
-            nonce.to_le_bytes().as_slice(),
-        ]);
-        if next_hash.le(&difficulty) {
-            break;
+    }
+
+    fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    fn post(&self, worker: usize, req: WebWorkerRequest) {
+        if let Some(tx) = self.workers.get(worker) {
+            tx.send((req, self.generation.load(Ordering::Relaxed))).ok();
         }
-        nonce += 1;
     }
-    Some(MiningResult {
-        hash: next_hash,
-        nonce,
-    })
+
+    fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
 }
@@ -1,83 +1,125 @@
+mod backend;
+#[cfg(feature = "desktop")]
+mod desktop;
 mod web_worker;
 
-use base64::Engine;
+pub use backend::*;
+#[cfg(feature = "desktop")]
+pub use desktop::*;
+pub use ore_app_miner_core::{
+    best_response, find_next_hash, hash_reward, MiningStats, WebWorkerRequest, WebWorkerResponse,
+    WorkerStats,
+};
+pub use web_worker::*;
+
+use std::cell::Cell;
+
 use dioxus::prelude::*;
 use dioxus_sdk::utils::channel::UseChannel;
 use drillx::Solution;
 use lazy_static::lazy_static;
-use ore_api::state::Proof;
+use ore_api::{
+    consts::{ONE_MINUTE, TOLERANCE},
+    state::Proof,
+};
 use rand::Rng;
-use serde_wasm_bindgen::to_value;
 use solana_client_wasm::solana_sdk::{
-    blake3::Hash as Blake3Hash, compute_budget::ComputeBudgetInstruction, pubkey::Pubkey,
-    signature::Signature, transaction::Transaction,
+    blake3::Hash as Blake3Hash, pubkey::Pubkey, signature::Signature,
 };
-use web_sys::{window, Worker};
-use web_time::{Duration, Instant};
-pub use web_worker::*;
+use web_time::Duration;
 
 use crate::{
-    gateway::{self, proof_pubkey, GatewayError, GatewayResult},
+    gateway::{FeeAction, GatewayError, GatewayResult, PriorityFee},
     hooks::{
-        use_gateway, use_wallet_adapter::WalletAdapter, MinerStatus, MinerStatusMessage,
-        MinerToolbarState, PowerLevel, ReadMinerToolbarState, UpdateMinerToolbarState,
+        use_gateway,
+        use_wallet_adapter::{sign_transaction, WalletAdapter},
+        LookupTable, MinerStatus, MinerStatusMessage, MinerToolbarState, PowerLevel,
+        ReadMinerToolbarState, TargetDifficulty, UpdateMinerToolbarState,
     },
     metrics::{self, AppEvent},
+    tx,
 };
 
-// Number of physical cores on machine
+// Number of logical processors on machine
 lazy_static! {
-    pub static ref WEB_WORKERS: usize = fetch_logical_processors();
-}
-
-fn fetch_logical_processors() -> usize {
-    let window = window().expect("should have a window");
-    let navigator = window.navigator();
-    navigator.hardware_concurrency() as usize
+    pub static ref WEB_WORKERS: usize = Backend::concurrency();
 }
 
 /// Miner encapsulates the logic needed to efficiently mine for valid hashes according to the application runtime and hardware.
 pub struct Miner {
     power_level: Signal<PowerLevel>,
-    web_workers: Vec<Worker>,
+    priority_fee: Signal<PriorityFee>,
+    lookup_table: Signal<LookupTable>,
+    target_difficulty: Signal<TargetDifficulty>,
+    backend: Backend,
+    // Id of the newest batch. Responses from older batches are stale.
+    batch: Cell<u64>,
 }
 
 impl Miner {
-    pub fn new(cx: UseChannel<WebWorkerResponse>, power_level: Signal<PowerLevel>) -> Self {
+    pub fn new(
+        cx: UseChannel<WebWorkerResponse>,
+        power_level: Signal<PowerLevel>,
+        priority_fee: Signal<PriorityFee>,
+        lookup_table: Signal<LookupTable>,
+        target_difficulty: Signal<TargetDifficulty>,
+    ) -> Self {
         Self {
             power_level: power_level.clone(),
-            web_workers: (0..*WEB_WORKERS)
-                .map(|_| create_web_worker(cx.clone()))
-                .collect(),
+            priority_fee,
+            lookup_table,
+            target_difficulty,
+            backend: Backend::new(cx),
+            batch: Cell::new(0),
         }
     }
 
-    pub async fn start_mining(&self, challenge: [u8; 32], offset: u64, cutoff_time: u64) {
-        self.start_mining_web(challenge, offset, cutoff_time).await;
+    pub fn batch(&self) -> u64 {
+        self.batch.get()
     }
 
-    pub async fn start_mining_web(&self, challenge: [u8; 32], offset: u64, cutoff_time: u64) {
-        let nonce = u64::MAX.saturating_div(self.web_workers.len() as u64);
+    /// Stops the current batch. Responses still in flight are stale.
+    pub fn cancel(&self) {
+        self.batch.set(self.batch.get().wrapping_add(1));
+        self.backend.cancel();
+    }
+
+    pub async fn start_mining(&self, challenge: [u8; 32], offset: u64, cutoff_time: u64) {
+        let target_difficulty = match *self.target_difficulty.read() {
+            TargetDifficulty::Off => None,
+            target_difficulty => match use_gateway().get_config().await {
+                Ok(config) => {
+                    target_difficulty.target(config.min_difficulty as u32, config.base_reward_rate)
+                }
+                Err(err) => {
+                    log::error!("Failed to get config: {:?}", err);
+                    None
+                }
+            },
+        };
+        let batch = self.batch.get().wrapping_add(1);
+        self.batch.set(batch);
+        let workers = self.backend.workers();
+        let nonce = u64::MAX.saturating_div(workers as u64);
         let power_level = self.power_level.read().0.saturating_sub(1) as usize;
-        log::info!("Start mining web...");
-        for (i, web_worker) in self.web_workers.iter().enumerate() {
+        log::info!("Start mining...");
+        for i in 0..workers {
             let nonce = nonce.saturating_mul(i as u64).saturating_add(offset);
             if i.le(&power_level) {
                 log::info!("Posting message: {:?} {:?}", i, nonce);
-                web_worker
-                    .post_message(
-                        &to_value(
-                            &(WebWorkerRequest {
-                                challenge,
-                                nonce: nonce.to_le_bytes(),
-                                offset,
-                                cutoff_time,
-                                power_level,
-                            }),
-                        )
-                        .unwrap(),
-                    )
-                    .unwrap();
+                self.backend.post(
+                    i,
+                    WebWorkerRequest {
+                        challenge,
+                        nonce: nonce.to_le_bytes(),
+                        offset,
+                        cutoff_time,
+                        power_level,
+                        target_difficulty,
+                        batch,
+                        worker: i,
+                    },
+                );
             }
         }
     }
@@ -103,20 +145,14 @@ impl Miner {
 
         // Get best solution
         let gateway = use_gateway();
-        let mut challenge = [0; 32];
-        let mut offset = 0;
-        let mut best_difficulty = 0;
-        let mut best_solution = Solution::new([0; 16], [0; 8]);
-        let mut best_hash = [0u8; 32];
-        for msg in messages {
-            if msg.difficulty.gt(&best_difficulty) {
-                best_solution = drillx::Solution::new(msg.digest, msg.nonce);
-                best_difficulty = msg.difficulty;
-                best_hash = msg.hash;
-                offset = msg.offset;
-                challenge = msg.challenge;
-            }
-        }
+        let Some(best) = best_response(messages) else {
+            return;
+        };
+        let challenge = best.challenge;
+        let offset = best.offset;
+        let best_difficulty = best.difficulty;
+        let best_solution = Solution::new(best.digest, best.nonce);
+        let best_hash = best.hash;
 
         // Kickoff new batch
         if let Ok(config) = gateway.get_config().await {
@@ -129,8 +165,29 @@ impl Miner {
         // Update toolbar state
         toolbar_state.set_display_hash(Blake3Hash::new_from_array(best_hash));
 
+        // Hold an early hash until the program accepts it, or it is rejected as spam
+        if best.reached_target() {
+            let batch = self.batch();
+            if let Err(err) = wait_for_spam_cutoff(authority).await {
+                log::error!("Failed to wait for spam cutoff: {:?}", err);
+            }
+            if toolbar_state.status().ne(&MinerStatus::Active) || self.batch().ne(&batch) {
+                return;
+            }
+        }
+
         // Submit solution
-        match submit_solution(authority, best_solution, toolbar_state).await {
+        let priority_fee = *self.priority_fee.read();
+        let lookup_table = *self.lookup_table.read();
+        match submit_solution(
+            authority,
+            best_solution,
+            priority_fee,
+            lookup_table,
+            toolbar_state,
+        )
+        .await
+        {
             // Start mining again
             Ok(_sig) => {
                 metrics::track(AppEvent::Mine);
@@ -163,7 +220,9 @@ impl Miner {
                     GatewayError::SignatureDenied => {
                         toolbar_state.set_status_message(MinerStatusMessage::SignatureDenied);
                     }
-                    _ => {}
+                    err => {
+                        toolbar_state.set_status_message(MinerStatusMessage::Error(err));
+                    }
                 }
             }
         }
@@ -173,90 +232,58 @@ impl Miner {
 pub async fn submit_solution(
     authority: Pubkey,
     solution: Solution,
+    priority_fee: PriorityFee,
+    lookup_table: LookupTable,
     toolbar_state: &mut Signal<MinerToolbarState>,
 ) -> GatewayResult<Signature> {
     // Build tx
     toolbar_state.set_status_message(MinerStatusMessage::Submitting(0));
     let gateway = use_gateway();
-    let price = gateway::get_recent_priority_fee_estimate(false).await;
-    let cu_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(500_000);
-    let cu_price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-    let mut ixs = vec![cu_limit_ix, cu_price_ix];
-    ixs.push(ore_api::instruction::auth(proof_pubkey(authority)));
-    ixs.push(ore_api::instruction::mine(
-        authority,
-        authority,
-        find_bus(),
-        solution,
-    ));
-    let mut tx = Transaction::new_with_payer(&ixs, Some(&authority));
+    let price = gateway
+        .get_priority_fee(priority_fee, FeeAction::Mine)
+        .await;
+    let builder = tx::mine(authority, solution, find_bus()).priority_fee(price);
+    let mut tx = gateway.build_transaction(builder, lookup_table.0).await?;
 
-    // Sign and submit the tx
+    // Sign and submit the tx, signing again with a new blockhash each time it expires
+    let mut i = 1;
     loop {
-        // Set recent blockhash
-        if let Ok(blockhash) = gateway.get_latest_blockhash().await {
-            tx.message.recent_blockhash = blockhash;
-        }
-        log::info!("TX: {:?}", tx);
-
-        // Get signature from user
-        let mut eval = eval(
-            r#"
-        let msg = await dioxus.recv();
-        let signed = await window.OreTxSigner({b64: msg});
-        dioxus.send(signed);
-        "#,
-        );
-        let bytes = bincode::serialize(&tx).unwrap();
-        let b64 = base64::engine::general_purpose::STANDARD.encode(bytes);
-        if let Ok(_res) = eval.send(serde_json::Value::String(b64)) {
-            // Parse response
-            let res = eval.recv().await;
-            if let Ok(serde_json::Value::String(string)) = res {
-                if let Some(tx) = base64::engine::general_purpose::STANDARD
-                    .decode(string)
-                    .ok()
-                    .and_then(|buffer| bincode::deserialize(&buffer).ok())
-                {
-                    // Submit the tx
-                    let mut i = 1;
-                    let timer = Instant::now();
-                    'submit: loop {
-                        toolbar_state.set_status_message(MinerStatusMessage::Submitting(i));
-                        match gateway.rpc.send_transaction(&tx).await {
-                            Ok(sig) => {
-                                // Confirm the signature
-                                log::info!("Sig: {:?}", sig);
-                                let confirmed = gateway.confirm_signature(sig).await;
-                                if confirmed.is_ok() {
-                                    return Ok(tx.signatures[0]);
-                                }
-
-                                // Break if 1 min has passed
-                                if timer.elapsed().as_secs().gt(&60) {
-                                    break 'submit;
-                                }
-                            }
-                            Err(err) => {
-                                // TODO
-                                log::error!("Err: {:?}", err);
-                                break 'submit;
-                            }
-                        }
-                        i += 1;
-                    }
-                }
-            } else {
-                log::error!("Signature denied A");
-                return Err(GatewayError::SignatureDenied);
+        toolbar_state.set_status_message(MinerStatusMessage::Submitting(i));
+        tx.message
+            .set_recent_blockhash(gateway.get_latest_blockhash().await?);
+        let signed = sign_transaction(&tx).await?;
+        match gateway.send_and_confirm(&signed).await {
+            Ok(sig) => {
+                log::info!("Sig: {:?}", sig);
+                gateway.invalidate(&gateway.account_keys(&signed.message).await);
+                return Ok(sig);
+            }
+            Err(GatewayError::TransactionExpired(sig)) => {
+                log::info!("Expired: {:?}", sig);
+            }
+            Err(err) => {
+                log::error!("Err: {:?}", err);
+                return Err(err);
             }
-        } else {
-            log::error!("Signature denied B");
-            return Err(GatewayError::SignatureDenied);
         }
+        i += 1;
     }
+}
 
-    // Ok(tx.signatures[0])
+/// Sleeps until the earliest time the program accepts the proof's next hash.
+async fn wait_for_spam_cutoff(authority: Pubkey) -> GatewayResult<()> {
+    let gateway = use_gateway();
+    let proof = gateway.get_proof(authority).await?;
+    let clock = gateway.get_clock().await?;
+    let wait = proof
+        .last_hash_at
+        .saturating_add(ONE_MINUTE)
+        .saturating_sub(TOLERANCE)
+        .saturating_sub(clock.unix_timestamp);
+    if wait.gt(&0) {
+        async_std::task::sleep(Duration::from_secs(wait as u64)).await;
+    }
+    Ok(())
 }
 
 fn find_bus() -> Pubkey {
@@ -1,30 +1,23 @@
+use std::{cell::Cell, rc::Rc};
+
 use dioxus_sdk::utils::channel::UseChannel;
+use ore_app_miner_core::Search;
 use serde::{Deserialize, Serialize};
 use serde_wasm_bindgen::{from_value, to_value};
 use wasm_bindgen::prelude::*;
-use web_sys::{DedicatedWorkerGlobalScope, MessageEvent, Worker, WorkerOptions, WorkerType};
-use web_time::Instant;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{
+    window, DedicatedWorkerGlobalScope, MessageChannel, MessageEvent, Worker, WorkerOptions,
+    WorkerType,
+};
 
-/// Mining request for web workers
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WebWorkerRequest {
-    pub challenge: [u8; 32],
-    pub nonce: [u8; 8],
-    pub offset: u64,
-    pub cutoff_time: u64,
-    pub power_level: usize,
-}
+use super::{MinerBackend, WebWorkerRequest, WebWorkerResponse};
 
-/// Mining response for web workers
+/// Message from the app to a web worker
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WebWorkerResponse {
-    pub hash: [u8; 32],
-    pub digest: [u8; 16],
-    pub nonce: [u8; 8],
-    pub difficulty: u32,
-    pub offset: u64,
-    pub challenge: [u8; 32],
-    pub power_level: usize,
+pub enum WebWorkerMessage {
+    Mine(WebWorkerRequest),
+    Cancel,
 }
 
 #[wasm_bindgen]
@@ -34,28 +27,101 @@ pub fn start_worker() {
     let scope = DedicatedWorkerGlobalScope::unchecked_from_js_ref(js_value);
     let scope_ = scope.clone();
 
+    // Bumped by each cancel message, which stops every search started before it
+    let generation = Rc::new(Cell::new(0u64));
+
     scope.set_onmessage(Some(&js_sys::Function::unchecked_from_js(
         Closure::<dyn Fn(MessageEvent)>::new(move |e: MessageEvent| {
-            let req: WebWorkerRequest = from_value(e.data()).unwrap();
-            let res = find_next_hash(
-                req.challenge,
-                req.nonce,
-                req.offset,
-                req.cutoff_time,
-                req.power_level,
-            );
-            scope_.post_message(&to_value(&res).unwrap()).unwrap();
+            match from_value::<WebWorkerMessage>(e.data()).unwrap() {
+                WebWorkerMessage::Mine(req) => {
+                    let scope = scope_.clone();
+                    let generation = generation.clone();
+                    spawn_local(async move {
+                        let res = search(&req, generation).await;
+                        scope.post_message(&to_value(&res).unwrap()).unwrap();
+                    });
+                }
+                WebWorkerMessage::Cancel => generation.set(generation.get().wrapping_add(1)),
+            }
         })
         .into_js_value(),
     )))
 }
 
+// Searches in short intervals, yielding between them so the worker can receive cancel messages.
+// A cancelled search still responds with the work it did, for telemetry.
+async fn search(req: &WebWorkerRequest, generation: Rc<Cell<u64>>) -> WebWorkerResponse {
+    let started = generation.get();
+    let mut search = Search::new(req.challenge, u64::from_le_bytes(req.nonce));
+    let channel = MessageChannel::new().unwrap();
+    while !search.step_interval(req) {
+        yield_now(&channel).await;
+        if generation.get().ne(&started) {
+            break;
+        }
+    }
+    channel.port1().close();
+    channel.port2().close();
+    search.response(req)
+}
+
+// Yields to the event loop by posting to a message channel. Unlike timers, messages are neither
+// clamped to a minimum delay nor throttled in background tabs.
+async fn yield_now(channel: &MessageChannel) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        channel.port1().set_onmessage(Some(&resolve));
+    });
+    channel.port2().post_message(&JsValue::NULL).ok();
+    JsFuture::from(promise).await.ok();
+}
+
 fn worker_options() -> WorkerOptions {
     let mut options = WorkerOptions::new();
     options.type_(WorkerType::Module);
     options
 }
 
+/// Mines in web workers, one per logical processor reported by the browser.
+#[cfg(not(feature = "desktop"))]
+pub struct WebWorkerPool {
+    workers: Vec<Worker>,
+}
+
+#[cfg(not(feature = "desktop"))]
+impl MinerBackend for WebWorkerPool {
+    fn concurrency() -> usize {
+        let window = window().expect("should have a window");
+        let navigator = window.navigator();
+        navigator.hardware_concurrency() as usize
+    }
+
+    fn new(cx: UseChannel<WebWorkerResponse>) -> Self {
+        WebWorkerPool {
+            workers: (0..Self::concurrency())
+                .map(|_| create_web_worker(cx.clone()))
+                .collect(),
+        }
+    }
+
+    fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    fn post(&self, worker: usize, req: WebWorkerRequest) {
+        if let Some(web_worker) = self.workers.get(worker) {
+            let msg = WebWorkerMessage::Mine(req);
+            web_worker.post_message(&to_value(&msg).unwrap()).unwrap();
+        }
+    }
+
+    fn cancel(&self) {
+        let msg = to_value(&WebWorkerMessage::Cancel).unwrap();
+        for web_worker in self.workers.iter() {
+            web_worker.post_message(&msg).unwrap();
+        }
+    }
+}
+
 pub fn create_web_worker(cx: UseChannel<WebWorkerResponse>) -> Worker {
     let worker = Worker::new_with_options("worker.js", &worker_options()).unwrap();
 
@@ -83,53 +149,3 @@ pub fn create_web_worker(cx: UseChannel<WebWorkerResponse>) -> Worker {
 
     worker
 }
-
-pub fn find_next_hash(
-    challenge: [u8; 32],
-    nonce: [u8; 8],
-    offset: u64,
-    cutoff_time: u64,
-    power_level: usize,
-) -> WebWorkerResponse {
-    let timer = Instant::now();
-    let mut i = 0;
-    let mut nonce = u64::from_le_bytes(nonce);
-    let mut best_hash = [0u8; 32];
-    let mut best_digest = [0u8; 16];
-    let mut best_nonce = [0u8; 8];
-    let mut best_difficulty = 0u32;
-    let mut memory = drillx::equix::SolverMemory::new();
-    loop {
-        if let Ok(hash) = drillx::hash_with_memory(&mut memory, &challenge, &nonce.to_le_bytes()) {
-            let difficulty = hash.difficulty();
-            if difficulty.gt(&best_difficulty) {
-                best_digest = hash.d;
-                best_difficulty = difficulty;
-                best_nonce = nonce.to_le_bytes();
-                best_hash = hash.h;
-            }
-        }
-
-        // Break if time has elapsed and batch size is processed
-        if nonce % 20 == 0 {
-            if timer.elapsed().as_secs().gt(&cutoff_time) {
-                if i.ge(&100) {
-                    break;
-                }
-            }
-        }
-
-        nonce += 1;
-        i += 1;
-    }
-
-    WebWorkerResponse {
-        digest: best_digest,
-        hash: best_hash,
-        nonce: best_nonce,
-        difficulty: best_difficulty,
-        offset: offset + i,
-        challenge,
-        power_level,
-    }
-}
@@ -2,7 +2,7 @@ use dioxus::prelude::*;
 
 use crate::components::{
     Claim, ClaimV1, Download, Home, Landing, Mine, MinerToolbarLayout, Navbar, OreTokenomics,
-    PageNotFound, Pay, Send, Settings, SimpleNavbar, Tx, Upgrade, User, WhatIsMining,
+    PageNotFound, Pay, Send, SendBatch, Settings, SimpleNavbar, Tx, Upgrade, User, WhatIsMining,
 };
 
 #[rustfmt::skip]
@@ -38,6 +38,8 @@ pub enum Route {
             Send {
                 to: String
             },
+            #[route("/send-batch")]
+            SendBatch {},
             #[route("/pay")]
             Pay {},
             #[route("/tx/:sig")]
@@ -0,0 +1,280 @@
+use std::str::FromStr;
+
+use ore_api::consts::TOKEN_DECIMALS;
+use solana_client_wasm::solana_sdk::{packet::PACKET_DATA_SIZE, pubkey::Pubkey};
+
+use crate::utils::parse_ui_amount;
+
+use super::{send, validate_memo, TxBuilder};
+
+/// A row of a batch send.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchTransfer {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub memo: Option<String>,
+}
+
+/// Parses a csv of `address,amount,memo` rows, with amounts in ORE. Memos are optional and
+/// may contain commas. Blank lines and a header row are skipped. Returns the line number of
+/// each row with its transfer, or why it is invalid.
+pub fn parse_batch_csv(csv: &str) -> Vec<(usize, Result<BatchTransfer, String>)> {
+    csv.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(i, line)| {
+            let is_header = line
+                .split(',')
+                .next()
+                .map(|field| unquote(field).eq_ignore_ascii_case("address"))
+                .unwrap_or(false);
+            !(i.eq(&0) && is_header)
+        })
+        .map(|(i, line)| (i + 1, parse_batch_row(line)))
+        .collect()
+}
+
+fn parse_batch_row(line: &str) -> Result<BatchTransfer, String> {
+    let mut fields = line.splitn(3, ',').map(unquote);
+    let recipient = fields
+        .next()
+        .and_then(|address| Pubkey::from_str(&address).ok())
+        .ok_or("Invalid address".to_string())?;
+    let amount = fields
+        .next()
+        .and_then(|amount| parse_ui_amount(&amount, TOKEN_DECIMALS))
+        .filter(|amount| amount.gt(&0))
+        .ok_or("Invalid amount".to_string())?;
+    let memo = fields.next().filter(|memo| !memo.is_empty());
+    if let Some(memo) = &memo {
+        validate_memo(memo)?;
+    }
+    Ok(BatchTransfer {
+        recipient,
+        amount,
+        memo,
+    })
+}
+
+// Trims a csv field and strips the quotes around it, if any
+fn unquote(field: &str) -> String {
+    let field = field.trim();
+    match field
+        .strip_prefix('"')
+        .and_then(|field| field.strip_suffix('"'))
+    {
+        Some(field) => field.replace("\"\"", "\""),
+        None => field.to_string(),
+    }
+}
+
+/// Packs transfers into as few transactions as fit, in order. Each transfer creates its
+/// recipient's token account and carries its memo. Returns the indices of the transfers
+/// each transaction holds, or the index of a transfer too large for a transaction of its
+/// own. Transactions are sized without lookup tables, which only make them smaller.
+pub fn pack_transfers(
+    signer: Pubkey,
+    transfers: &[BatchTransfer],
+    priority_fee: u64,
+) -> Vec<(Vec<usize>, Result<TxBuilder, String>)> {
+    let empty = || TxBuilder::new(signer).priority_fee(priority_fee);
+    let mut packed = vec![];
+    let mut indices: Vec<usize> = vec![];
+    let mut builder = empty();
+    for (i, transfer) in transfers.iter().enumerate() {
+        let mut ix = send(signer, transfer.recipient, transfer.amount);
+        if let Some(memo) = &transfer.memo {
+            ix = ix.memo(memo);
+        }
+        let mut candidate = builder.clone().append(ix.clone());
+        if !fits(&candidate) && !indices.is_empty() {
+            packed.push((
+                std::mem::take(&mut indices),
+                Ok(std::mem::replace(&mut builder, empty())),
+            ));
+            candidate = builder.clone().append(ix);
+        }
+        if fits(&candidate) {
+            builder = candidate;
+            indices.push(i);
+        } else {
+            packed.push((
+                vec![i],
+                Err("Transfer too large for a transaction".to_string()),
+            ));
+        }
+    }
+    if !indices.is_empty() {
+        packed.push((indices, Ok(builder)));
+    }
+    packed
+}
+
+// Whether a transaction fits in a packet once signed
+fn fits(builder: &TxBuilder) -> bool {
+    builder
+        .build()
+        .ok()
+        .and_then(|tx| bincode::serialized_size(&tx).ok())
+        .map(|size| size.le(&(PACKET_DATA_SIZE as u64)))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::MAX_MEMO_BYTES;
+
+    fn address() -> String {
+        Pubkey::new_unique().to_string()
+    }
+
+    fn ore(amount: &str) -> u64 {
+        parse_ui_amount(amount, TOKEN_DECIMALS).unwrap()
+    }
+
+    fn transfer(memo: Option<&str>) -> BatchTransfer {
+        BatchTransfer {
+            recipient: Pubkey::new_unique(),
+            amount: ore("1"),
+            memo: memo.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parses_rows_with_line_numbers() {
+        let (a, b) = (address(), address());
+        let csv = format!("{},1.5\n\n{},2,thanks\n", a, b);
+        let rows = parse_batch_csv(&csv);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, 1);
+        assert_eq!(
+            rows[0].1,
+            Ok(BatchTransfer {
+                recipient: Pubkey::from_str(&a).unwrap(),
+                amount: ore("1.5"),
+                memo: None,
+            })
+        );
+        assert_eq!(rows[1].0, 3);
+        assert_eq!(rows[1].1.as_ref().unwrap().memo.as_deref(), Some("thanks"));
+    }
+
+    #[test]
+    fn skips_header_only_on_first_line() {
+        let a = address();
+        let rows = parse_batch_csv(&format!("\"Address\",amount,memo\n{},1\n", a));
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].0, 2);
+        assert!(rows[0].1.is_ok());
+
+        let rows = parse_batch_csv(&format!("{},1\naddress,amount\n", a));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].1, Err("Invalid address".to_string()));
+    }
+
+    #[test]
+    fn unquotes_fields_and_keeps_commas_in_memos() {
+        let a = address();
+        let csv = format!(
+            "\"{}\", \"0.25\" ,\"hello, \"\"world\"\"\"\n{},1,a,b,c",
+            a, a
+        );
+        let rows = parse_batch_csv(&csv);
+        let first = rows[0].1.as_ref().unwrap();
+        assert_eq!(first.recipient, Pubkey::from_str(&a).unwrap());
+        assert_eq!(first.amount, ore("0.25"));
+        assert_eq!(first.memo.as_deref(), Some("hello, \"world\""));
+        assert_eq!(rows[1].1.as_ref().unwrap().memo.as_deref(), Some("a,b,c"));
+    }
+
+    #[test]
+    fn parses_amounts_without_rounding() {
+        let a = address();
+        let csv = format!("{},0.1\n{},1.005\n{},1.00000000001", a, a, a);
+        let amounts: Vec<u64> = parse_batch_csv(&csv)
+            .into_iter()
+            .map(|(_, row)| row.unwrap().amount)
+            .collect();
+        assert_eq!(
+            amounts,
+            vec![10_000_000_000, 100_500_000_000, 100_000_000_001]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_rows() {
+        let a = address();
+        let long_memo = "x".repeat(MAX_MEMO_BYTES + 1);
+        let csv = [
+            "not an address,1".to_string(),
+            a.clone(),
+            format!("{},0", a),
+            format!("{},-1", a),
+            format!("{},NaN", a),
+            format!("{},0.000000000001", a),
+            format!("{},1e3", a),
+            format!("{},1,\"\"", a),
+            format!("{},1,{}", a, long_memo),
+        ]
+        .join("\n");
+        let rows: Vec<Result<BatchTransfer, String>> = parse_batch_csv(&csv)
+            .into_iter()
+            .map(|(_, row)| row)
+            .collect();
+        assert_eq!(rows[0], Err("Invalid address".to_string()));
+        for row in &rows[1..7] {
+            assert_eq!(row, &Err("Invalid amount".to_string()));
+        }
+        assert_eq!(rows[7].as_ref().unwrap().memo, None);
+        assert_eq!(
+            rows[8],
+            Err(format!(
+                "Memo too long ({}/{} bytes)",
+                MAX_MEMO_BYTES + 1,
+                MAX_MEMO_BYTES
+            ))
+        );
+    }
+
+    #[test]
+    fn packs_nothing_for_no_transfers() {
+        assert!(pack_transfers(Pubkey::new_unique(), &[], 0).is_empty());
+    }
+
+    #[test]
+    fn packs_transfers_in_order_into_full_packets() {
+        let signer = Pubkey::new_unique();
+        let transfers: Vec<BatchTransfer> = (0..24).map(|_| transfer(Some("memo"))).collect();
+        let packed = pack_transfers(signer, &transfers, 1_000);
+        assert!(packed.len().gt(&1));
+        let indices: Vec<usize> = packed.iter().flat_map(|(i, _)| i.clone()).collect();
+        assert_eq!(indices, (0..24).collect::<Vec<usize>>());
+        for (i, (indices, builder)) in packed.iter().enumerate() {
+            let builder = builder.as_ref().unwrap();
+            assert!(fits(builder));
+            // Each transaction is full, so the next transfer didn't fit in it
+            if let Some((next, _)) = packed.get(i + 1) {
+                let next = &transfers[next[0]];
+                let ix = send(signer, next.recipient, next.amount).memo("memo");
+                assert!(!fits(&builder.clone().append(ix)));
+            }
+            assert!(!indices.is_empty());
+        }
+    }
+
+    #[test]
+    fn rejects_a_transfer_too_large_for_a_packet() {
+        let signer = Pubkey::new_unique();
+        let memo = "x".repeat(PACKET_DATA_SIZE);
+        let transfers = [transfer(None), transfer(Some(&memo)), transfer(None)];
+        let packed = pack_transfers(signer, &transfers, 0);
+        assert_eq!(packed.len(), 3);
+        assert_eq!(packed[0].0, vec![0]);
+        assert!(packed[0].1.is_ok());
+        assert_eq!(packed[1].0, vec![1]);
+        assert!(packed[1].1.is_err());
+        assert_eq!(packed[2].0, vec![2]);
+        assert!(packed[2].1.is_ok());
+    }
+}
@@ -0,0 +1,514 @@
+use std::fmt::Debug;
+
+use drillx::Solution;
+use ore_api::consts::{
+    BUS_ADDRESSES, MINT_ADDRESS, MINT_V1_ADDRESS, NOOP_PROGRAM_ID, TOKEN_DECIMALS,
+    TOKEN_DECIMALS_V1,
+};
+use solana_client_wasm::solana_sdk::{
+    address_lookup_table, compute_budget, native_token::lamports_to_sol, pubkey::Pubkey,
+    system_instruction::SystemInstruction, system_program,
+};
+use solana_extra_wasm::program::{
+    spl_associated_token_account::{self, get_associated_token_address},
+    spl_token::{self, instruction::TokenInstruction},
+};
+
+use super::MEMO_PROGRAM_ID;
+use crate::utils::format_ui_amount;
+
+/// An instruction of a confirmed transaction, decoded for display. Instructions of
+/// programs the app doesn't know are left undecoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    pub program_id: Pubkey,
+    pub program: String,
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+    pub accounts: Vec<Pubkey>,
+    /// Whether the instruction was invoked by another program.
+    pub inner: bool,
+}
+
+type Decoded = (String, Vec<(String, String)>);
+
+pub fn decode_instruction(
+    program_id: Pubkey,
+    accounts: Vec<Pubkey>,
+    data: &[u8],
+    inner: bool,
+) -> DecodedInstruction {
+    let (program, decoded) = if program_id.eq(&ore_api::ID) {
+        ("ORE", decode_ore(&accounts, data))
+    } else if program_id.eq(&ore_api_v1::ID) {
+        ("ORE v1", decode_ore_v1(data))
+    } else if program_id.eq(&ore_relayer_api::ID) {
+        ("ORE Relayer", decode_ore_relayer(data))
+    } else if program_id.eq(&NOOP_PROGRAM_ID) {
+        ("Noop", decode_noop(data))
+    } else if program_id.eq(&spl_token::id()) {
+        ("Token", decode_token(&accounts, data))
+    } else if program_id.eq(&spl_associated_token_account::id()) {
+        ("Associated Token", decode_associated_token(data))
+    } else if program_id.eq(&compute_budget::id()) {
+        ("Compute Budget", decode_compute_budget(data))
+    } else if program_id.eq(&system_program::id()) {
+        ("System", decode_system(data))
+    } else if program_id.eq(&MEMO_PROGRAM_ID) {
+        ("Memo", decode_memo(data))
+    } else if program_id.eq(&address_lookup_table::program::id()) {
+        ("Address Lookup Table", None)
+    } else {
+        ("Unknown", None)
+    };
+    let (name, fields) = decoded.unwrap_or_else(|| {
+        (
+            "Unknown".to_string(),
+            vec![("Data".to_string(), bs58::encode(data).into_string())],
+        )
+    });
+    DecodedInstruction {
+        program_id,
+        program: program.to_string(),
+        name,
+        fields,
+        accounts,
+        inner,
+    }
+}
+
+fn decode_ore(accounts: &[Pubkey], data: &[u8]) -> Option<Decoded> {
+    use ore_api::instruction::OreInstruction;
+    let (discriminator, args) = data.split_first()?;
+    let ix = match *discriminator {
+        d if d.eq(&(OreInstruction::Claim as u8)) => {
+            decoded("Claim", vec![("Amount", ore_amount(read_u64(args, 0)?))])
+        }
+        d if d.eq(&(OreInstruction::Close as u8)) => decoded("Close", vec![]),
+        d if d.eq(&(OreInstruction::Mine as u8)) => {
+            let digest: [u8; 16] = args.get(0..16)?.try_into().ok()?;
+            let nonce: [u8; 8] = args.get(16..24)?.try_into().ok()?;
+            let difficulty = Solution::new(digest, nonce).to_hash().difficulty();
+            let mut fields = vec![
+                ("Difficulty", difficulty.to_string()),
+                ("Nonce", u64::from_le_bytes(nonce).to_string()),
+            ];
+            if let Some(bus) = accounts
+                .iter()
+                .find_map(|account| BUS_ADDRESSES.iter().position(|bus| bus.eq(account)))
+            {
+                fields.push(("Bus", bus.to_string()));
+            }
+            decoded("Mine", fields)
+        }
+        d if d.eq(&(OreInstruction::Open as u8)) => decoded("Open", vec![]),
+        d if d.eq(&(OreInstruction::Reset as u8)) => decoded("Reset", vec![]),
+        d if d.eq(&(OreInstruction::Stake as u8)) => {
+            decoded("Stake", vec![("Amount", ore_amount(read_u64(args, 0)?))])
+        }
+        d if d.eq(&(OreInstruction::Update as u8)) => decoded("Update", vec![]),
+        d if d.eq(&(OreInstruction::Upgrade as u8)) => decoded(
+            "Upgrade",
+            vec![("Amount", ore_v1_amount(read_u64(args, 0)?))],
+        ),
+        d if d.eq(&(OreInstruction::Initialize as u8)) => decoded("Initialize", vec![]),
+        _ => return None,
+    };
+    Some(ix)
+}
+
+fn decode_ore_v1(data: &[u8]) -> Option<Decoded> {
+    use ore_api_v1::instruction::OreInstruction;
+    let (discriminator, args) = data.split_first()?;
+    let ix = match *discriminator {
+        d if d.eq(&(OreInstruction::Reset as u8)) => decoded("Reset", vec![]),
+        d if d.eq(&(OreInstruction::Register as u8)) => decoded("Register", vec![]),
+        d if d.eq(&(OreInstruction::Mine as u8)) => {
+            decoded("Mine", vec![("Nonce", read_u64(args, 32)?.to_string())])
+        }
+        d if d.eq(&(OreInstruction::Claim as u8)) => {
+            decoded("Claim", vec![("Amount", ore_v1_amount(read_u64(args, 0)?))])
+        }
+        _ => return None,
+    };
+    Some(ix)
+}
+
+// The relayer's instruction enum isn't public, so discriminators are read from the
+// instructions its builders return
+fn decode_ore_relayer(data: &[u8]) -> Option<Decoded> {
+    let (discriminator, args) = data.split_first()?;
+    let claim =
+        ore_relayer_api::instruction::claim(Pubkey::default(), Pubkey::default(), 0).data[0];
+    let migrate = ore_relayer_api::instruction::migrate(
+        Pubkey::default(),
+        Pubkey::default(),
+        Pubkey::default(),
+    )
+    .data[0];
+    if discriminator.eq(&claim) {
+        Some(decoded(
+            "Claim",
+            vec![("Amount", ore_amount(read_u64(args, 0)?))],
+        ))
+    } else if discriminator.eq(&migrate) {
+        Some(decoded("Migrate", vec![]))
+    } else {
+        None
+    }
+}
+
+// Auth instructions prove which proof a transaction mines for, so later instructions can
+// check it
+fn decode_noop(data: &[u8]) -> Option<Decoded> {
+    let proof = Pubkey::try_from(data).ok()?;
+    Some(decoded("Auth", vec![("Proof", proof.to_string())]))
+}
+
+// Amounts are shown in ORE when the mint is ORE. Unchecked instructions don't carry the
+// mint's decimals, so amounts of other mints are shown in base units.
+fn decode_token(accounts: &[Pubkey], data: &[u8]) -> Option<Decoded> {
+    let ix = TokenInstruction::unpack(data).ok()?;
+    let account = |i: usize| accounts.get(i).copied();
+    let fields = match ix {
+        TokenInstruction::Transfer { amount } | TokenInstruction::Approve { amount } => {
+            // The source is only known to be ORE if it is its owner's ORE token account
+            let mint = [MINT_ADDRESS, MINT_V1_ADDRESS].into_iter().find(|mint| {
+                account(2)
+                    .map(|owner| get_associated_token_address(&owner, mint))
+                    .eq(&account(0))
+            });
+            vec![("Amount", token_amount(amount, mint, None))]
+        }
+        TokenInstruction::MintTo { amount } => {
+            vec![("Amount", token_amount(amount, account(0), None))]
+        }
+        TokenInstruction::Burn { amount } => {
+            vec![("Amount", token_amount(amount, account(1), None))]
+        }
+        TokenInstruction::TransferChecked { amount, decimals }
+        | TokenInstruction::ApproveChecked { amount, decimals }
+        | TokenInstruction::BurnChecked { amount, decimals } => {
+            vec![("Amount", token_amount(amount, account(1), Some(decimals)))]
+        }
+        TokenInstruction::MintToChecked { amount, decimals } => {
+            vec![("Amount", token_amount(amount, account(0), Some(decimals)))]
+        }
+        _ => vec![],
+    };
+    Some(decoded(&variant_name(&ix), fields))
+}
+
+fn token_amount(amount: u64, mint: Option<Pubkey>, decimals: Option<u8>) -> String {
+    match (mint, decimals) {
+        (Some(mint), _) if mint.eq(&MINT_ADDRESS) => ore_amount(amount),
+        (Some(mint), _) if mint.eq(&MINT_V1_ADDRESS) => ore_v1_amount(amount),
+        (_, Some(decimals)) => format_ui_amount(amount, decimals),
+        (_, None) => format!("{} (base units)", amount),
+    }
+}
+
+fn decode_associated_token(data: &[u8]) -> Option<Decoded> {
+    let name = match data.first() {
+        None | Some(0) => "Create",
+        Some(1) => "CreateIdempotent",
+        Some(2) => "RecoverNested",
+        _ => return None,
+    };
+    Some(decoded(name, vec![]))
+}
+
+fn decode_compute_budget(data: &[u8]) -> Option<Decoded> {
+    let (discriminator, args) = data.split_first()?;
+    let ix = match *discriminator {
+        1 => decoded(
+            "RequestHeapFrame",
+            vec![("Bytes", read_u32(args)?.to_string())],
+        ),
+        2 => decoded(
+            "SetComputeUnitLimit",
+            vec![("Units", read_u32(args)?.to_string())],
+        ),
+        3 => decoded(
+            "SetComputeUnitPrice",
+            vec![("Microlamports", read_u64(args, 0)?.to_string())],
+        ),
+        4 => decoded(
+            "SetLoadedAccountsDataSizeLimit",
+            vec![("Bytes", read_u32(args)?.to_string())],
+        ),
+        _ => return None,
+    };
+    Some(ix)
+}
+
+fn decode_system(data: &[u8]) -> Option<Decoded> {
+    let ix = bincode::deserialize::<SystemInstruction>(data).ok()?;
+    let fields = match ix {
+        SystemInstruction::Transfer { lamports } => {
+            vec![("Amount", format!("{} SOL", lamports_to_sol(lamports)))]
+        }
+        SystemInstruction::CreateAccount {
+            lamports, space, ..
+        } => vec![
+            ("Amount", format!("{} SOL", lamports_to_sol(lamports))),
+            ("Space", space.to_string()),
+        ],
+        _ => vec![],
+    };
+    Some(decoded(&variant_name(&ix), fields))
+}
+
+fn decode_memo(data: &[u8]) -> Option<Decoded> {
+    let memo = std::str::from_utf8(data).ok()?;
+    Some(decoded("Memo", vec![("Memo", memo.to_string())]))
+}
+
+fn decoded(name: &str, fields: Vec<(&str, String)>) -> Decoded {
+    (
+        name.to_string(),
+        fields
+            .into_iter()
+            .map(|(label, value)| (label.to_string(), value))
+            .collect(),
+    )
+}
+
+// The name of an enum variant, without its fields
+fn variant_name<T: Debug>(value: &T) -> String {
+    format!("{:?}", value)
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn ore_amount(amount: u64) -> String {
+    format!("{} ORE", format_ui_amount(amount, TOKEN_DECIMALS))
+}
+
+fn ore_v1_amount(amount: u64) -> String {
+    format!("{} OREv1", format_ui_amount(amount, TOKEN_DECIMALS_V1))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(data: &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(0..4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_client_wasm::solana_sdk::{
+        compute_budget::ComputeBudgetInstruction, instruction::Instruction,
+    };
+
+    use super::*;
+    use crate::gateway::{ore_token_account_address, ore_token_account_address_v1, proof_pubkey};
+
+    const ORE: u64 = 10u64.pow(TOKEN_DECIMALS as u32);
+    const ORE_V1: u64 = 10u64.pow(TOKEN_DECIMALS_V1 as u32);
+
+    fn decode(ix: Instruction) -> Decoded {
+        let accounts = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        let ix = decode_instruction(ix.program_id, accounts, &ix.data, false);
+        (ix.name, ix.fields)
+    }
+
+    fn decode_raw(program_id: Pubkey, data: &[u8]) -> Decoded {
+        let ix = decode_instruction(program_id, vec![], data, false);
+        (ix.name, ix.fields)
+    }
+
+    #[test]
+    fn decodes_ore_instructions() {
+        let signer = Pubkey::new_unique();
+        let tokens = ore_token_account_address(signer);
+        assert_eq!(
+            decode(ore_api::instruction::claim(signer, tokens, ORE * 3 / 2)),
+            decoded("Claim", vec![("Amount", "1.5 ORE".to_string())])
+        );
+        assert_eq!(
+            decode(ore_api::instruction::stake(signer, tokens, 2 * ORE)),
+            decoded("Stake", vec![("Amount", "2 ORE".to_string())])
+        );
+        assert_eq!(
+            decode(ore_api::instruction::upgrade(
+                signer,
+                tokens,
+                ore_token_account_address_v1(signer),
+                ORE_V1 / 4
+            )),
+            decoded("Upgrade", vec![("Amount", "0.25 OREv1".to_string())])
+        );
+        assert_eq!(
+            decode(ore_api::instruction::open(signer, signer, signer)),
+            decoded("Open", vec![])
+        );
+        assert_eq!(
+            decode(ore_api::instruction::auth(proof_pubkey(signer))),
+            decoded("Auth", vec![("Proof", proof_pubkey(signer).to_string())])
+        );
+    }
+
+    #[test]
+    fn decodes_ore_mine() {
+        let signer = Pubkey::new_unique();
+        let solution = Solution::new([7; 16], 42u64.to_le_bytes());
+        let difficulty = Solution::new([7; 16], 42u64.to_le_bytes())
+            .to_hash()
+            .difficulty();
+        assert_eq!(
+            decode(ore_api::instruction::mine(
+                signer,
+                signer,
+                BUS_ADDRESSES[5],
+                solution
+            )),
+            decoded(
+                "Mine",
+                vec![
+                    ("Difficulty", difficulty.to_string()),
+                    ("Nonce", "42".to_string()),
+                    ("Bus", "5".to_string()),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn decodes_ore_v1_instructions() {
+        let signer = Pubkey::new_unique();
+        assert_eq!(
+            decode(ore_api_v1::instruction::claim(
+                signer,
+                ore_token_account_address_v1(signer),
+                ORE_V1 * 5 / 2
+            )),
+            decoded("Claim", vec![("Amount", "2.5 OREv1".to_string())])
+        );
+    }
+
+    #[test]
+    fn decodes_token_amounts_by_mint() {
+        let owner = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let transfer = |source: Pubkey| {
+            spl_token::instruction::transfer(&spl_token::id(), &source, &other, &owner, &[], ORE)
+                .unwrap()
+        };
+
+        // Unchecked transfers of ORE are recognized by the owner's token account
+        assert_eq!(
+            decode(transfer(ore_token_account_address(owner))),
+            decoded("Transfer", vec![("Amount", "1 ORE".to_string())])
+        );
+        assert_eq!(
+            decode(transfer(ore_token_account_address_v1(owner))),
+            decoded("Transfer", vec![("Amount", "100 OREv1".to_string())])
+        );
+        assert_eq!(
+            decode(transfer(Pubkey::new_unique())),
+            decoded(
+                "Transfer",
+                vec![("Amount", "100000000000 (base units)".to_string())]
+            )
+        );
+
+        // Checked instructions name the mint and its decimals
+        assert_eq!(
+            decode(
+                spl_token::instruction::transfer_checked(
+                    &spl_token::id(),
+                    &other,
+                    &MINT_ADDRESS,
+                    &other,
+                    &owner,
+                    &[],
+                    ORE / 10,
+                    TOKEN_DECIMALS
+                )
+                .unwrap()
+            ),
+            decoded("TransferChecked", vec![("Amount", "0.1 ORE".to_string())])
+        );
+        assert_eq!(
+            decode(
+                spl_token::instruction::transfer_checked(
+                    &spl_token::id(),
+                    &other,
+                    &Pubkey::new_unique(),
+                    &other,
+                    &owner,
+                    &[],
+                    1_500_000,
+                    6
+                )
+                .unwrap()
+            ),
+            decoded("TransferChecked", vec![("Amount", "1.5".to_string())])
+        );
+        assert_eq!(
+            decode(
+                spl_token::instruction::mint_to(
+                    &spl_token::id(),
+                    &MINT_ADDRESS,
+                    &other,
+                    &owner,
+                    &[],
+                    2 * ORE
+                )
+                .unwrap()
+            ),
+            decoded("MintTo", vec![("Amount", "2 ORE".to_string())])
+        );
+        assert_eq!(
+            decode(
+                spl_token::instruction::burn(
+                    &spl_token::id(),
+                    &other,
+                    &MINT_V1_ADDRESS,
+                    &owner,
+                    &[],
+                    ORE_V1
+                )
+                .unwrap()
+            ),
+            decoded("Burn", vec![("Amount", "1 OREv1".to_string())])
+        );
+    }
+
+    #[test]
+    fn decodes_compute_budget_instructions() {
+        assert_eq!(
+            decode(ComputeBudgetInstruction::set_compute_unit_limit(200_000)),
+            decoded("SetComputeUnitLimit", vec![("Units", "200000".to_string())])
+        );
+        assert_eq!(
+            decode(ComputeBudgetInstruction::set_compute_unit_price(5_000)),
+            decoded(
+                "SetComputeUnitPrice",
+                vec![("Microlamports", "5000".to_string())]
+            )
+        );
+        assert_eq!(
+            decode_raw(compute_budget::id(), &[2, 0x40, 0x0d, 0x03, 0x00]),
+            decoded("SetComputeUnitLimit", vec![("Units", "200000".to_string())])
+        );
+    }
+
+    #[test]
+    fn leaves_unknown_data_undecoded() {
+        let unknown = decoded(
+            "Unknown",
+            vec![("Data", bs58::encode([9, 9]).into_string())],
+        );
+        assert_eq!(decode_raw(Pubkey::new_unique(), &[9, 9]), unknown);
+        assert_eq!(decode_raw(compute_budget::id(), &[9, 9]), unknown);
+        assert_eq!(decode_raw(spl_token::id(), &[3, 9]).0, "Unknown");
+    }
+}
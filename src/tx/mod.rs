@@ -0,0 +1,293 @@
+mod batch;
+mod decode;
+mod ore;
+
+use solana_client_wasm::solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, CompileError, VersionedMessage},
+    pubkey,
+    pubkey::Pubkey,
+    signature::Signature,
+    system_program,
+    transaction::VersionedTransaction,
+};
+use solana_extra_wasm::program::{
+    spl_associated_token_account::{self, get_associated_token_address},
+    spl_token,
+};
+
+pub use batch::*;
+pub use decode::*;
+pub use ore::*;
+
+/// The most compute units a transaction may request.
+pub const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// The SPL Memo program.
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// The longest memo a transaction may carry, in bytes. Leaves room in the transaction for
+/// the instructions the memo is attached to.
+pub const MAX_MEMO_BYTES: usize = 256;
+
+/// Builds an unsigned v0 transaction from a list of instructions, prefixed with the compute
+/// budget instructions. The compute unit limit defaults to the max, so the transaction can
+/// be simulated before its limit is sized. The blockhash is left unset.
+#[derive(Clone, Debug)]
+pub struct TxBuilder {
+    payer: Pubkey,
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+    ixs: Vec<Instruction>,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl TxBuilder {
+    pub fn new(payer: Pubkey) -> Self {
+        TxBuilder {
+            payer,
+            compute_unit_limit: MAX_COMPUTE_UNITS,
+            compute_unit_price: 0,
+            ixs: vec![],
+            lookup_tables: vec![],
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
+    pub fn compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_unit_limit = units.min(MAX_COMPUTE_UNITS);
+        self
+    }
+
+    /// Sets the priority fee, in microlamports per compute unit.
+    pub fn priority_fee(mut self, price: u64) -> Self {
+        self.compute_unit_price = price;
+        self
+    }
+
+    pub fn instruction(mut self, ix: Instruction) -> Self {
+        self.ixs.push(ix);
+        self
+    }
+
+    /// Appends the instructions of another builder, so several actions can be batched
+    /// into one transaction. The other builder's compute budget is ignored.
+    pub fn append(mut self, other: TxBuilder) -> Self {
+        self.ixs.extend(other.ixs);
+        self
+    }
+
+    /// Loads accounts from the lookup tables instead of listing them in the message,
+    /// which leaves room for more instructions.
+    pub fn lookup_tables(mut self, lookup_tables: Vec<AddressLookupTableAccount>) -> Self {
+        self.lookup_tables = lookup_tables;
+        self
+    }
+
+    /// Creates the owner's token account for the mint, paid for by the payer. Does nothing
+    /// onchain if the account already exists, so there is no need to check for it first.
+    pub fn create_token_account(self, owner: Pubkey, mint: Pubkey) -> Self {
+        let payer = self.payer;
+        self.instruction(create_token_account_idempotent(payer, owner, mint))
+    }
+
+    /// Attaches a memo, signed by the payer.
+    pub fn memo(self, memo: &str) -> Self {
+        let payer = self.payer;
+        self.instruction(build_memo(memo, &[payer]))
+    }
+
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(
+            self.compute_unit_limit,
+        )];
+        if self.compute_unit_price.gt(&0) {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                self.compute_unit_price,
+            ));
+        }
+        ixs.extend(self.ixs.iter().cloned());
+        ixs
+    }
+
+    pub fn build(&self) -> Result<VersionedTransaction, CompileError> {
+        let message = v0::Message::try_compile(
+            &self.payer,
+            &self.instructions(),
+            &self.lookup_tables,
+            Hash::default(),
+        )?;
+        Ok(VersionedTransaction {
+            signatures: vec![Signature::default(); message.header.num_required_signatures as usize],
+            message: VersionedMessage::V0(message),
+        })
+    }
+}
+
+/// Builds an SPL Memo instruction. The memo program checks each given signer signed the
+/// transaction.
+pub fn build_memo(memo: &str, signers: &[Pubkey]) -> Instruction {
+    Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect(),
+        data: memo.as_bytes().to_vec(),
+    }
+}
+
+/// Checks a memo fits in a transaction. Memos are utf-8 by construction, so only the
+/// length needs checking.
+pub fn validate_memo(memo: &str) -> Result<(), String> {
+    if memo.len().gt(&MAX_MEMO_BYTES) {
+        return Err(format!(
+            "Memo too long ({}/{} bytes)",
+            memo.len(),
+            MAX_MEMO_BYTES
+        ));
+    }
+    Ok(())
+}
+
+/// Builds an associated token program CreateIdempotent instruction.
+pub fn create_token_account_idempotent(payer: Pubkey, owner: Pubkey, mint: Pubkey) -> Instruction {
+    Instruction {
+        program_id: spl_associated_token_account::id(),
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(get_associated_token_address(&owner, &mint), false),
+            AccountMeta::new_readonly(owner, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: vec![1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_budget_comes_first() {
+        let payer = Pubkey::new_unique();
+        let ix = build_memo("hi", &[payer]);
+        let ixs = TxBuilder::new(payer)
+            .compute_unit_limit(50_000)
+            .priority_fee(1_000)
+            .instruction(ix.clone())
+            .instructions();
+        assert_eq!(
+            ixs,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(50_000),
+                ComputeBudgetInstruction::set_compute_unit_price(1_000),
+                ix,
+            ]
+        );
+    }
+
+    #[test]
+    fn compute_budget_defaults_to_max_units_and_no_fee() {
+        let payer = Pubkey::new_unique();
+        let ixs = TxBuilder::new(payer).instructions();
+        assert_eq!(
+            ixs,
+            vec![ComputeBudgetInstruction::set_compute_unit_limit(
+                MAX_COMPUTE_UNITS
+            )]
+        );
+        let ixs = TxBuilder::new(payer)
+            .compute_unit_limit(MAX_COMPUTE_UNITS + 1)
+            .instructions();
+        assert_eq!(
+            ixs[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(MAX_COMPUTE_UNITS)
+        );
+    }
+
+    #[test]
+    fn append_keeps_only_the_first_compute_budget() {
+        let payer = Pubkey::new_unique();
+        let first = build_memo("first", &[payer]);
+        let second = build_memo("second", &[payer]);
+        let ixs = TxBuilder::new(payer)
+            .priority_fee(7)
+            .instruction(first.clone())
+            .append(
+                TxBuilder::new(payer)
+                    .priority_fee(9)
+                    .instruction(second.clone()),
+            )
+            .instructions();
+        assert_eq!(
+            ixs,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(MAX_COMPUTE_UNITS),
+                ComputeBudgetInstruction::set_compute_unit_price(7),
+                first,
+                second,
+            ]
+        );
+    }
+
+    #[test]
+    fn create_token_account_is_idempotent() {
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let ix = create_token_account_idempotent(payer, owner, mint);
+        assert_eq!(ix.program_id, spl_associated_token_account::id());
+        assert_eq!(ix.data, vec![1]);
+        assert_eq!(ix.accounts[0], AccountMeta::new(payer, true));
+        assert_eq!(
+            ix.accounts[1].pubkey,
+            get_associated_token_address(&owner, &mint)
+        );
+    }
+
+    #[test]
+    fn memo_is_signed_by_the_payer() {
+        let payer = Pubkey::new_unique();
+        let ixs = TxBuilder::new(payer).memo("thanks").instructions();
+        assert_eq!(ixs[1].program_id, MEMO_PROGRAM_ID);
+        assert_eq!(
+            ixs[1].accounts,
+            vec![AccountMeta::new_readonly(payer, true)]
+        );
+        assert_eq!(ixs[1].data, b"thanks".to_vec());
+    }
+
+    #[test]
+    fn validates_memo_size_in_bytes() {
+        assert!(validate_memo("").is_ok());
+        assert!(validate_memo(&"a".repeat(MAX_MEMO_BYTES)).is_ok());
+        assert_eq!(
+            validate_memo(&"a".repeat(MAX_MEMO_BYTES + 1)),
+            Err(format!(
+                "Memo too long ({}/{} bytes)",
+                MAX_MEMO_BYTES + 1,
+                MAX_MEMO_BYTES
+            ))
+        );
+        // Each of these characters takes 3 bytes
+        assert!(validate_memo(&"☕".repeat(MAX_MEMO_BYTES / 3)).is_ok());
+        assert!(validate_memo(&"☕".repeat(MAX_MEMO_BYTES / 3 + 1)).is_err());
+    }
+
+    #[test]
+    fn builds_a_transaction_for_every_signer() {
+        let payer = Pubkey::new_unique();
+        let tx = TxBuilder::new(payer).memo("hi").build().unwrap();
+        assert_eq!(tx.signatures.len(), 1);
+        assert_eq!(tx.message.static_account_keys()[0], payer);
+    }
+}
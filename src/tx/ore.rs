@@ -0,0 +1,305 @@
+use std::ops::Div;
+
+use drillx::Solution;
+use ore_api::consts::{BUS_ADDRESSES, CONFIG_ADDRESS, MINT_ADDRESS, TREASURY_ADDRESS};
+use solana_client_wasm::solana_sdk::{
+    address_lookup_table::instruction::{create_lookup_table, extend_lookup_table},
+    clock::Slot,
+    instruction::{AccountMeta, Instruction},
+    native_token::sol_to_lamports,
+    pubkey,
+    pubkey::Pubkey,
+    system_instruction,
+};
+use solana_extra_wasm::program::spl_token;
+
+use crate::gateway::{
+    escrow_pubkey, ore_token_account_address, ore_token_account_address_v1, proof_pubkey,
+    treasury_tokens_pubkey,
+};
+
+use super::TxBuilder;
+
+/// Amount of SOL to top up a miner's escrow with (~$2).
+pub const TOP_UP_AMOUNT: f64 = 0.02;
+
+/// Address which collects the 1% fee on top ups and new accounts.
+pub const COLLECTION_ADDRESS: Pubkey = pubkey!("tHCCE3KWKx8i8cDjX2DQ3Z7EMJkScAVwkfxdWz8SqgP");
+
+/// Claims mining rewards into the signer's token account. Miners with an escrow account
+/// claim through the relayer.
+pub fn claim(signer: Pubkey, amount: u64, escrow: bool) -> TxBuilder {
+    let token_account_address = ore_token_account_address(signer);
+    let ix = if escrow {
+        ore_relayer_api::instruction::claim(signer, token_account_address, amount)
+    } else {
+        ore_api::instruction::claim(signer, token_account_address, amount)
+    };
+    TxBuilder::new(signer)
+        .create_token_account(signer, ore_api::consts::MINT_ADDRESS)
+        .instruction(ix)
+}
+
+/// Claims v1 mining rewards into the signer's v1 token account.
+pub fn claim_v1(signer: Pubkey, amount: u64) -> TxBuilder {
+    TxBuilder::new(signer)
+        .create_token_account(signer, ore_api::consts::MINT_V1_ADDRESS)
+        .instruction(ore_api_v1::instruction::claim(
+            signer,
+            ore_token_account_address_v1(signer),
+            amount,
+        ))
+}
+
+/// Sends ORE to the recipient, creating their token account if it does not exist.
+pub fn send(signer: Pubkey, recipient: Pubkey, amount: u64) -> TxBuilder {
+    let from_token_account = ore_token_account_address(signer);
+    let to_token_account = ore_token_account_address(recipient);
+    TxBuilder::new(signer)
+        .create_token_account(recipient, ore_api::consts::MINT_ADDRESS)
+        .instruction(
+            spl_token::instruction::transfer(
+                &spl_token::id(),
+                &from_token_account,
+                &to_token_account,
+                &signer,
+                &[&signer],
+                amount,
+            )
+            .expect("valid transfer instruction"),
+        )
+}
+
+/// Pays a Solana Pay transfer request. As the spec requires, the memo comes just before the
+/// transfer, the transfer is checked against the mint, and the references are added to the
+/// transfer as read only keys.
+pub fn pay(
+    signer: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+    references: &[Pubkey],
+    memo: Option<&str>,
+) -> TxBuilder {
+    let mut transfer = spl_token::instruction::transfer_checked(
+        &spl_token::id(),
+        &ore_token_account_address(signer),
+        &ore_api::consts::MINT_ADDRESS,
+        &ore_token_account_address(recipient),
+        &signer,
+        &[&signer],
+        amount,
+        ore_api::consts::TOKEN_DECIMALS,
+    )
+    .expect("valid transfer instruction");
+    transfer.accounts.extend(
+        references
+            .iter()
+            .map(|reference| AccountMeta::new_readonly(*reference, false)),
+    );
+    let mut builder =
+        TxBuilder::new(signer).create_token_account(recipient, ore_api::consts::MINT_ADDRESS);
+    if let Some(memo) = memo {
+        builder = builder.memo(memo);
+    }
+    builder.instruction(transfer)
+}
+
+/// Stakes ORE from the signer's token account into their proof.
+pub fn stake(signer: Pubkey, amount: u64) -> TxBuilder {
+    TxBuilder::new(signer).instruction(ore_api::instruction::stake(
+        signer,
+        ore_token_account_address(signer),
+        amount,
+    ))
+}
+
+/// Upgrades v1 ORE to v2 ORE.
+pub fn upgrade(signer: Pubkey, amount: u64) -> TxBuilder {
+    TxBuilder::new(signer)
+        .create_token_account(signer, ore_api::consts::MINT_ADDRESS)
+        .instruction(ore_api::instruction::upgrade(
+            signer,
+            ore_token_account_address(signer),
+            ore_token_account_address_v1(signer),
+            amount,
+        ))
+}
+
+/// Opens a proof account for the signer, so they can start mining.
+pub fn open(signer: Pubkey) -> TxBuilder {
+    TxBuilder::new(signer)
+        .instruction(ore_api::instruction::open(signer, signer, signer))
+        .instruction(collection_fee(signer, sol_to_lamports(TOP_UP_AMOUNT)))
+}
+
+/// Tops up the signer's escrow account with SOL to pay for mining transactions.
+pub fn top_up(signer: Pubkey) -> TxBuilder {
+    let amount = sol_to_lamports(TOP_UP_AMOUNT);
+    TxBuilder::new(signer)
+        .instruction(system_instruction::transfer(
+            &signer,
+            &escrow_pubkey(signer),
+            amount,
+        ))
+        .instruction(collection_fee(signer, amount))
+}
+
+/// Migrates the signer's escrow proof to a proof they own.
+pub fn migrate(signer: Pubkey) -> TxBuilder {
+    TxBuilder::new(signer)
+        .create_token_account(signer, ore_api::consts::MINT_ADDRESS)
+        .instruction(ore_relayer_api::instruction::migrate(
+            signer, signer, signer,
+        ))
+}
+
+/// Submits a mining solution to the given bus.
+pub fn mine(authority: Pubkey, solution: Solution, bus: Pubkey) -> TxBuilder {
+    TxBuilder::new(authority)
+        .instruction(ore_api::instruction::auth(proof_pubkey(authority)))
+        .instruction(ore_api::instruction::mine(
+            authority, authority, bus, solution,
+        ))
+}
+
+/// Creates a lookup table owned by the signer, holding the ORE accounts their transactions
+/// use. Returns the builder and the address of the new table. The table can be used from
+/// the slot after this transaction lands.
+pub fn create_ore_lookup_table(signer: Pubkey, recent_slot: Slot) -> (TxBuilder, Pubkey) {
+    let (create_ix, address) = create_lookup_table(signer, signer, recent_slot);
+    let mut addresses = BUS_ADDRESSES.to_vec();
+    addresses.extend([
+        CONFIG_ADDRESS,
+        MINT_ADDRESS,
+        TREASURY_ADDRESS,
+        treasury_tokens_pubkey(),
+        proof_pubkey(signer),
+        ore_token_account_address(signer),
+    ]);
+    let builder = TxBuilder::new(signer)
+        .instruction(create_ix)
+        .instruction(extend_lookup_table(
+            address,
+            signer,
+            Some(signer),
+            addresses,
+        ));
+    (builder, address)
+}
+
+// Transfers the 1% fee on an amount to the collection address
+fn collection_fee(signer: Pubkey, amount: u64) -> Instruction {
+    system_instruction::transfer(&signer, &COLLECTION_ADDRESS, amount.div(100))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_client_wasm::solana_sdk::compute_budget::ComputeBudgetInstruction;
+    use solana_extra_wasm::program::spl_associated_token_account;
+
+    use super::*;
+    use crate::tx::{build_memo, create_token_account_idempotent, MAX_COMPUTE_UNITS};
+
+    // The builder's own instructions, after the compute budget
+    fn user_instructions(builder: TxBuilder) -> Vec<Instruction> {
+        builder.instructions().split_off(1)
+    }
+
+    fn creates_token_account(builder: TxBuilder) -> bool {
+        builder
+            .instructions()
+            .iter()
+            .any(|ix| ix.program_id.eq(&spl_associated_token_account::id()))
+    }
+
+    #[test]
+    fn creates_token_accounts_only_for_the_receiver() {
+        let signer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        assert_eq!(
+            user_instructions(send(signer, recipient, 1))[0],
+            create_token_account_idempotent(signer, recipient, MINT_ADDRESS)
+        );
+        assert_eq!(
+            user_instructions(claim(signer, 1, false))[0],
+            create_token_account_idempotent(signer, signer, MINT_ADDRESS)
+        );
+        assert_eq!(
+            user_instructions(claim_v1(signer, 1))[0],
+            create_token_account_idempotent(signer, signer, ore_api::consts::MINT_V1_ADDRESS)
+        );
+        assert!(creates_token_account(claim(signer, 1, true)));
+        assert!(creates_token_account(pay(signer, recipient, 1, &[], None)));
+        assert!(creates_token_account(upgrade(signer, 1)));
+        assert!(creates_token_account(migrate(signer)));
+
+        // These only spend from accounts which must already exist
+        assert!(!creates_token_account(stake(signer, 1)));
+        assert!(!creates_token_account(open(signer)));
+        assert!(!creates_token_account(top_up(signer)));
+        assert!(!creates_token_account(mine(
+            signer,
+            Solution::new([0; 16], [0; 8]),
+            BUS_ADDRESSES[0]
+        )));
+    }
+
+    #[test]
+    fn mine_authenticates_then_mines() {
+        let authority = Pubkey::new_unique();
+        let bus = BUS_ADDRESSES[3];
+        let builder = mine(authority, Solution::new([1; 16], [2; 8]), bus).priority_fee(5_000);
+        assert_eq!(
+            builder.instructions(),
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(MAX_COMPUTE_UNITS),
+                ComputeBudgetInstruction::set_compute_unit_price(5_000),
+                ore_api::instruction::auth(proof_pubkey(authority)),
+                ore_api::instruction::mine(
+                    authority,
+                    authority,
+                    bus,
+                    Solution::new([1; 16], [2; 8])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn pay_puts_the_memo_before_the_transfer() {
+        let signer = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let references = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let ixs = user_instructions(pay(signer, recipient, 5, &references, Some("order 1")));
+        assert_eq!(ixs.len(), 3);
+        assert_eq!(ixs[1], build_memo("order 1", &[signer]));
+        assert_eq!(ixs[2].program_id, spl_token::id());
+        let keys = &ixs[2].accounts[ixs[2].accounts.len() - 2..];
+        assert_eq!(
+            keys,
+            [
+                AccountMeta::new_readonly(references[0], false),
+                AccountMeta::new_readonly(references[1], false),
+            ]
+        );
+        assert_eq!(
+            user_instructions(pay(signer, recipient, 5, &[], None)).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn open_and_top_up_pay_the_collection_fee() {
+        let signer = Pubkey::new_unique();
+        let amount = sol_to_lamports(TOP_UP_AMOUNT);
+        let fee = system_instruction::transfer(&signer, &COLLECTION_ADDRESS, amount / 100);
+        assert_eq!(user_instructions(open(signer))[1], fee);
+        assert_eq!(
+            user_instructions(top_up(signer)),
+            vec![
+                system_instruction::transfer(&signer, &escrow_pubkey(signer), amount),
+                fee,
+            ]
+        );
+    }
+}
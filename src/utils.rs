@@ -1 +1,93 @@
+/// Parses a decimal amount of tokens into base units, without rounding. Amounts with signs,
+/// exponents, no digits or more significant decimals than the token has are rejected.
+pub fn parse_ui_amount(amount: &str, decimals: u8) -> Option<u64> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len().gt(&(decimals as usize)) {
+        return None;
+    }
+    let whole = match whole {
+        "" => 0,
+        whole => whole.parse::<u64>().ok()?,
+    };
+    let fraction = match fraction {
+        "" => 0,
+        fraction => format!("{:0<width$}", fraction, width = decimals as usize)
+            .parse::<u64>()
+            .ok()?,
+    };
+    whole
+        .checked_mul(10u64.checked_pow(decimals as u32)?)?
+        .checked_add(fraction)
+}
 
+/// Formats an amount of base units as a decimal amount of tokens, without rounding or
+/// trailing zeros.
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let scale = 10u64.pow(decimals as u32);
+    let fraction = format!("{:0>width$}", amount % scale, width = decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (amount / scale).to_string()
+    } else {
+        format!("{}.{}", amount / scale, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_amounts_exactly() {
+        assert_eq!(parse_ui_amount("1", 11), Some(100_000_000_000));
+        assert_eq!(parse_ui_amount("0.1", 11), Some(10_000_000_000));
+        assert_eq!(parse_ui_amount("1.005", 11), Some(100_500_000_000));
+        assert_eq!(parse_ui_amount("0.00000000001", 11), Some(1));
+        assert_eq!(parse_ui_amount("0.000000000010", 11), Some(1));
+        assert_eq!(parse_ui_amount(".5", 2), Some(50));
+        assert_eq!(parse_ui_amount("5.", 2), Some(500));
+        assert_eq!(parse_ui_amount("0", 2), Some(0));
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        for amount in [
+            "",
+            ".",
+            "-1",
+            "+1",
+            "1e3",
+            "1.2.3",
+            " 1",
+            "abc",
+            "0.000000000001",
+            "184467440737.09551616",
+        ] {
+            assert_eq!(parse_ui_amount(amount, 11), None, "{}", amount);
+        }
+    }
+
+    #[test]
+    fn formats_amounts_exactly() {
+        assert_eq!(format_ui_amount(100_000_000_000, 11), "1");
+        assert_eq!(format_ui_amount(100_500_000_000, 11), "1.005");
+        assert_eq!(format_ui_amount(1, 11), "0.00000000001");
+        assert_eq!(format_ui_amount(0, 11), "0");
+        assert_eq!(format_ui_amount(u64::MAX, 0), u64::MAX.to_string());
+        for amount in [1, 10_000_000_000, 123_456_789_012_345, u64::MAX] {
+            assert_eq!(
+                parse_ui_amount(&format_ui_amount(amount, 11), 11),
+                Some(amount)
+            );
+        }
+    }
+}
@@ -1,11 +1,11 @@
-import React, { useMemo, useCallback } from 'react';
+import React, { useMemo, useCallback, useState } from 'react';
 import ReactDOM from 'react-dom/client';
 import { ConnectionProvider, WalletProvider, useWallet } from '@solana/wallet-adapter-react';
 import {
   BaseWalletMultiButton,
   WalletModalProvider,
 } from '@solana/wallet-adapter-react-ui';
-import { Transaction } from '@solana/web3.js';
+import { VersionedTransaction } from '@solana/web3.js';
 import * as buffer from "buffer";
 window.Buffer = buffer.Buffer;
 
@@ -22,8 +22,23 @@ const LABELS = {
     'no-wallet': 'Connect',
 };
 
+// Use the same rpc endpoint as the app, as selected in settings. Read again before every
+// signature, since the cluster can change without a reload.
+function rpcEndpoint() {
+  try {
+    const endpoints = JSON.parse(window.localStorage.getItem("endpoints"));
+    if (endpoints && endpoints.rpc_urls && endpoints.rpc_urls.length > 0) {
+      return endpoints.rpc_urls[0];
+    }
+  } catch (err) {
+    console.log(err);
+  }
+  return "https://api.mainnet-beta.solana.com";
+}
+
 export const Wallet = () => {
-  const endpoint = "http://localhost:8899";
+  const [endpoint, setEndpoint] = useState(rpcEndpoint);
+  const refreshEndpoint = useCallback(() => setEndpoint(rpcEndpoint()), []);
   const wallets = useMemo(
     () => [
     ],
@@ -37,7 +52,7 @@ export const Wallet = () => {
           <BaseWalletMultiButton labels={LABELS} />
           { /* Your app's components go here, nested within the context providers. */}
           <Dispatcher />
-          <SignTransaction />
+          <SignTransaction refreshEndpoint={refreshEndpoint} />
         </WalletModalProvider>
       </WalletProvider>
     </ConnectionProvider>
@@ -79,11 +94,12 @@ function Dispatcher() {
   }, [publicKey]);
 }
 
-function SignTransaction() {
+function SignTransaction({ refreshEndpoint }) {
   const { publicKey, signTransaction } = useWallet();
   const callback = useCallback(async (msg) => {
+    refreshEndpoint();
     try {
-      const tx = Transaction.from(
+      const tx = VersionedTransaction.deserialize(
         Buffer.from(
           msg.b64,
           "base64"
@@ -92,13 +108,13 @@ function SignTransaction() {
       const signed = await signTransaction(
         tx
       );
-      return signed
-        .serialize()
-        .toString("base64");
+      return Buffer.from(
+        signed.serialize()
+      ).toString("base64");
     } catch (err) {
       console.log(err);
     }
-  }, [publicKey]);
+  }, [publicKey, refreshEndpoint]);
   window.OreTxSigner = callback;
   return
 }